@@ -0,0 +1,67 @@
+//! # Eigen Module
+//!
+//! Eigendecomposition for symmetric matrices using the cyclic Jacobi method,
+//! which converges reliably for the (often rank deficient) scatter and
+//! covariance matrices used in this crate.
+
+use crate::linalg::{BaseMatrix, Matrix};
+
+/// The maximum number of sweeps over the off-diagonal elements.
+const MAX_SWEEPS: usize = 100;
+
+/// Computes the eigenvalues and eigenvectors of a symmetric matrix.
+///
+/// #### Parameters:
+/// - matrix: The symmetric matrix to decompose.
+///
+/// #### Returns:
+/// - Tuple of the eigenvalues and a matrix with the matching unit eigenvectors
+///   as its columns.
+///
+pub(crate) fn symmetric_eigendecomp(matrix: &Matrix<f64>) -> (Vec<f64>, Matrix<f64>) {
+    let n = matrix.rows();
+    let mut a = matrix.clone();
+    let mut vectors = Matrix::<f64>::identity(n);
+    let total_norm = a.iter().map(|x| x * x).sum::<f64>();
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal_norm: f64 = (0..n)
+            .flat_map(|p| (0..n).filter(move |&q| q != p).map(move |q| (p, q)))
+            .map(|(p, q)| a[[p, q]] * a[[p, q]])
+            .sum();
+        if off_diagonal_norm <= f64::EPSILON * f64::EPSILON * total_norm {
+            break;
+        }
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[[p, q]] == 0.0 {
+                    continue;
+                }
+                // Rotation angle that zeroes a[p, q].
+                let theta = (a[[q, q]] - a[[p, p]]) / (2.0 * a[[p, q]]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[[k, p]], a[[k, q]]);
+                    a[[k, p]] = c * akp - s * akq;
+                    a[[k, q]] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[[p, k]], a[[q, k]]);
+                    a[[p, k]] = c * apk - s * aqk;
+                    a[[q, k]] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (vectors[[k, p]], vectors[[k, q]]);
+                    vectors[[k, p]] = c * vkp - s * vkq;
+                    vectors[[k, q]] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    ((0..n).map(|i| a[[i, i]]).collect(), vectors)
+}
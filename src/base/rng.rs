@@ -0,0 +1,56 @@
+//! # RNG Module
+//!
+//! Central place for creating the random number generators used throughout
+//! the crate so that shuffling, splitting and sampling all draw from the same,
+//! reproducible source.
+//!
+//! ## Examples
+//! ```
+//! use rand::Rng;
+//! use rust_ml::base::rng::seeded_rng;
+//!
+//! let mut first = seeded_rng(7);
+//! let mut second = seeded_rng(7);
+//!
+//! assert_eq!(first.gen::<u64>(), second.gen::<u64>());
+//! ```
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The seed used when the caller does not provide one.
+pub const DEFAULT_SEED: u64 = 42;
+
+/// Creates a random number generator seeded with the given value. The same
+/// seed will always produce the same sequence of values.
+///
+/// #### Parameters:
+/// - seed: The seed for the random number generator.
+///
+/// #### Returns:
+/// - The seeded random number generator.
+///
+pub fn seeded_rng(seed: u64) -> impl Rng {
+    StdRng::seed_from_u64(seed)
+}
+
+/// Creates a random number generator seeded with the `DEFAULT_SEED`.
+pub fn default_rng() -> impl Rng {
+    seeded_rng(DEFAULT_SEED)
+}
+
+/// Draws a sample from the standard normal distribution using the Box-Muller
+/// transform.
+///
+/// #### Parameters:
+/// - rng: The random number generator to draw from.
+///
+/// #### Returns:
+/// - The normally distributed sample.
+///
+pub(crate) fn standard_normal<R: Rng>(rng: &mut R) -> f64 {
+    // Shift the first uniform sample into (0, 1] so the log is finite.
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
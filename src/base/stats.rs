@@ -0,0 +1,78 @@
+//! # Stats Module
+//!
+//! Small statistical helpers shared by the preprocessors and datasets.
+
+use crate::base::eigen::symmetric_eigendecomp;
+use crate::linalg::{Axes, BaseMatrix, Matrix, Vector};
+
+/// Computes the `q`-th quantile of already sorted values using linear
+/// interpolation between the closest ranks.
+///
+/// #### Parameters:
+/// - sorted: The values sorted in ascending order, must not be empty.
+/// - q: The quantile to compute, in the range [0, 1].
+///
+/// #### Returns:
+/// - The interpolated quantile value.
+///
+pub(crate) fn sorted_quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * fraction
+}
+
+/// Collects the values of a column and sorts them in ascending order.
+///
+/// #### Parameters:
+/// - values: Iterator over the column values.
+///
+/// #### Returns:
+/// - The sorted values.
+///
+pub(crate) fn sorted_values<'a, I: Iterator<Item = &'a f64>>(values: I) -> Vec<f64> {
+    let mut sorted: Vec<f64> = values.copied().collect();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+/// Fits an ordinary least squares regression with an intercept and returns
+/// its coefficient of determination. The normal equations are solved with the
+/// pseudo-inverse of `XᵀX`, so collinear features do not cause an error.
+///
+/// #### Parameters:
+/// - x: The feature matrix, one sample per row.
+/// - y: The target values.
+///
+/// #### Returns:
+/// - The R² of the fit, or NaN if the target is constant.
+///
+pub(crate) fn ols_r_squared(x: &Matrix<f64>, y: &Vector<f64>) -> f64 {
+    let n = y.size() as f64;
+    let y_centered = y - y.sum() / n;
+    let total_sum_squares = y_centered.iter().map(|v| v * v).sum::<f64>();
+    if total_sum_squares == 0.0 {
+        return f64::NAN;
+    }
+    if x.cols() == 0 {
+        return 0.0;
+    }
+
+    // Centering the features and target absorbs the intercept.
+    let x_mean = x.mean(Axes::Row);
+    let x_centered = x - Matrix::new(x.rows(), x.cols(), x_mean.data().repeat(x.rows()));
+    let x_transpose = x_centered.transpose();
+    let (values, vectors) = symmetric_eigendecomp(&(&x_transpose * &x_centered));
+    let tolerance = values.iter().cloned().fold(0.0, f64::max) * 1e-10;
+    let inverse_values = values
+        .iter()
+        .map(|&v| if v > tolerance { 1.0 / v } else { 0.0 })
+        .collect::<Vec<f64>>();
+    let pseudo_inverse = &vectors * Matrix::from_diag(&inverse_values) * vectors.transpose();
+    let coefficients = pseudo_inverse * (x_transpose * &y_centered);
+
+    let residuals = y_centered - x_centered * coefficients;
+    let residual_sum_squares = residuals.iter().map(|v| v * v).sum::<f64>();
+    1.0 - residual_sum_squares / total_sum_squares
+}
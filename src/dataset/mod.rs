@@ -27,15 +27,23 @@
 //! assert_eq!("label", dataset.target_column());
 //! ```
 
+use crate::base::eigen::symmetric_eigendecomp;
 use crate::base::error::{Error, ErrorKind};
+use crate::base::rng::seeded_rng;
+use crate::base::stats::{ols_r_squared, sorted_quantile, sorted_values};
 use crate::base::MLResult;
 use crate::linalg::Matrix;
-use crate::linalg::Vector;
+use crate::linalg::{Axes, BaseMatrix, Vector};
+use crate::preprocessing::encoders::labelencoder::LabelEncoderFitter;
 
 use csv::ReaderBuilder;
 use num::Float;
+use rand::seq::SliceRandom;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::fs::File;
+use std::hash::Hash;
+use std::io::Read;
 use std::path::Path;
 use std::str::FromStr;
 
@@ -43,6 +51,12 @@ use std::str::FromStr;
 pub mod iris;
 /// Module for Pokemon stats dataset.
 pub mod pokemon;
+/// Module for synthetic dataset generators.
+pub mod synthetic;
+
+/// Type alias for a dataset with a label encoded target, along with the
+/// label mapping used to encode it.
+pub type EncodedDataset = (Dataset<Matrix<f64>, Vector<f64>>, HashMap<String, f64>);
 
 /// Struct for a datatset.
 #[derive(Clone, Debug)]
@@ -96,6 +110,17 @@ where
     pub fn target_column(&self) -> &str {
         &self.target_column
     }
+
+    /// Consumes the dataset and returns its data, target, data columns and
+    /// target column.
+    pub(crate) fn into_parts(self) -> (X, Y, Vector<String>, String) {
+        (
+            self.data,
+            self.target,
+            self.data_columns,
+            self.target_column,
+        )
+    }
 }
 
 impl<X, Y> Dataset<Matrix<X>, Vector<Y>>
@@ -115,8 +140,51 @@ where
     ///
     pub fn from_csv<P: AsRef<Path>>(file_path: P, target_column: &str) -> MLResult<Self> {
         let file = File::open(file_path).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
-        // Create the csv reader from the file (assumes headers are available).
-        let mut rdr = ReaderBuilder::new().has_headers(true).from_reader(file);
+        Self::from_reader(file, target_column)
+    }
+
+    /// Creates a Dataset struct from CSV data held in a string, parsed the same
+    /// as `from_csv`.
+    ///
+    /// #### Parameters:
+    /// - data: The CSV data, including the header row.
+    /// - target_column: The target column name.
+    ///
+    /// #### Returns:
+    /// - The loaded dataset in an MLResult instance.
+    ///
+    pub fn from_csv_str(data: &str, target_column: &str) -> MLResult<Self> {
+        Self::from_reader(data.as_bytes(), target_column)
+    }
+
+    /// Creates a Dataset struct from a gzip-compressed CSV file. The file is
+    /// decompressed while it is read and otherwise parsed the same as `from_csv`.
+    ///
+    /// #### Parameters:
+    /// - filepath: A Path reference.
+    /// - target_column: The target column name.
+    ///
+    /// #### Returns:
+    /// - The loaded dataset in an MLResult instance.
+    ///
+    #[cfg(feature = "flate2")]
+    pub fn from_csv_gz<P: AsRef<Path>>(file_path: P, target_column: &str) -> MLResult<Self> {
+        let file = File::open(file_path).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        Self::from_reader(flate2::read::GzDecoder::new(file), target_column)
+    }
+
+    /// Helper function that parses the CSV data from any reader into a Dataset.
+    ///
+    /// #### Parameters:
+    /// - reader: The source of the CSV data.
+    /// - target_column: The target column name.
+    ///
+    /// #### Returns:
+    /// - The loaded dataset in an MLResult instance.
+    ///
+    fn from_reader<R: Read>(reader: R, target_column: &str) -> MLResult<Self> {
+        // Create the csv reader (assumes headers are available).
+        let mut rdr = ReaderBuilder::new().has_headers(true).from_reader(reader);
 
         let (headers, target_index) = process_headers(&mut rdr, target_column)?;
 
@@ -136,46 +204,1297 @@ where
                     })?;
                     target_values.push(record_target);
                 } else {
-                    let feature_value = X::from_str(feature).map_err(|_| {
-                        Error::new(
-                            ErrorKind::InvalidData,
-                            format!("Failed to parse value {} in column {}", feature, index),
-                        )
-                    })?;
-                    record_features.push(feature_value);
+                    record_features.push(parse_value(feature, index)?);
+                }
+            }
+            data_rows.push(record_features);
+        }
+        if data_rows.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "CSV contains no data rows",
+            ));
+        }
+        let row_dim = data_rows.len();
+        let col_dim = data_rows[0].len();
+
+        // Convert the accumulated data into a matrix for the dataset struct.
+        let flattened_data: Vec<X> = data_rows.into_iter().flatten().collect();
+        let data = Matrix::new(row_dim, col_dim, flattened_data);
+
+        Ok(Dataset::new(
+            data,
+            Vector::new(target_values),
+            Vector::new(
+                headers
+                    .iter()
+                    .filter(|&h| h != target_column)
+                    .map(|s| s.to_string())
+                    .collect::<Vec<String>>(),
+            ),
+            String::from(target_column),
+        ))
+    }
+}
+
+impl<Y> Dataset<Matrix<f64>, Vector<Y>>
+where
+    Y: Clone + Debug,
+{
+    /// Builds a new Dataset from the rows at the given indices, keeping
+    /// the column headers.
+    fn select_rows(&self, indices: &[usize]) -> Self {
+        Dataset::new(
+            self.data.select_rows(indices),
+            Vector::new(
+                indices
+                    .iter()
+                    .map(|&i| self.target[i].clone())
+                    .collect::<Vec<Y>>(),
+            ),
+            self.data_columns.clone(),
+            self.target_column.clone(),
+        )
+    }
+
+    /// Keeps the rows whose feature values satisfy a predicate.
+    ///
+    /// #### Parameters:
+    /// - predicate: Function that receives the feature values of a row, in
+    ///   column order, and returns whether to keep the row.
+    ///
+    /// #### Returns:
+    /// - Dataset with the matching rows, in their original order.
+    ///
+    pub fn filter_by_feature<F: Fn(&[f64]) -> bool>(&self, predicate: F) -> Self {
+        let indices: Vec<usize> = self
+            .data
+            .row_iter()
+            .enumerate()
+            .filter(|(_, row)| predicate(row.raw_slice()))
+            .map(|(idx, _)| idx)
+            .collect();
+        self.select_rows(&indices)
+    }
+
+    /// Shuffles the rows with the given seed and splits them into batches, so
+    /// that every training epoch can see the data in a different order by
+    /// passing a different seed.
+    ///
+    /// #### Parameters:
+    /// - batch_size: The number of rows per batch. The last batch holds the
+    ///   remaining rows and may be smaller.
+    /// - seed: The seed for the random number generator.
+    ///
+    /// #### Returns:
+    /// - Iterator over the batches.
+    ///
+    /// ## Panics
+    ///
+    /// If `batch_size` is zero.
+    ///
+    pub fn epoch_batches(
+        &self,
+        batch_size: usize,
+        seed: u64,
+    ) -> impl Iterator<Item = Dataset<Matrix<f64>, Vector<Y>>> + '_ {
+        assert!(batch_size > 0, "Batch size must be at least 1");
+        let mut indices: Vec<usize> = (0..self.data.rows()).collect();
+        indices.shuffle(&mut seeded_rng(seed));
+        let batches: Vec<Vec<usize>> = indices.chunks(batch_size).map(|c| c.to_vec()).collect();
+        batches
+            .into_iter()
+            .map(move |batch| self.select_rows(&batch))
+    }
+
+    /// Returns the number of feature columns.
+    pub fn n_features(&self) -> usize {
+        self.data.cols()
+    }
+
+    /// Returns the number of samples (rows).
+    pub fn n_samples(&self) -> usize {
+        self.data.rows()
+    }
+
+    /// Estimates the bytes used by the feature matrix and the column names,
+    /// the target specific part is added by `memory_bytes`.
+    fn feature_memory_bytes(&self) -> usize {
+        let header_bytes: usize = self.data_columns.iter().map(|c| c.len()).sum();
+        self.data.rows() * self.data.cols() * std::mem::size_of::<f64>()
+            + header_bytes
+            + self.target_column.len()
+    }
+
+    /// Appends one sample to the end of the dataset.
+    ///
+    /// #### Parameters:
+    /// - features: The feature values of the sample, in column order.
+    /// - target: The target value of the sample.
+    ///
+    /// #### Returns:
+    /// - Empty MLResult or an InvalidData error if the number of features does
+    ///   not match the number of columns.
+    ///
+    pub fn push_row(&mut self, features: &[f64], target: Y) -> MLResult<()> {
+        if features.len() != self.data_columns.size() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Row has {} features but the dataset has {} columns",
+                    features.len(),
+                    self.data_columns.size()
+                ),
+            ));
+        }
+
+        // Move the storage out so the new row is appended without copying the
+        // existing data.
+        let rows = self.data.rows();
+        let mut data = std::mem::replace(&mut self.data, Matrix::new(0, 0, Vec::new())).into_vec();
+        data.extend_from_slice(features);
+        self.data = Matrix::new(rows + 1, features.len(), data);
+        let mut targets = std::mem::replace(&mut self.target, Vector::new(Vec::new())).into_vec();
+        targets.push(target);
+        self.target = Vector::new(targets);
+        Ok(())
+    }
+
+    /// Checks that the feature matrix, target and column names agree in size.
+    ///
+    /// #### Returns:
+    /// - Empty MLResult or an InvalidState error describing the mismatch.
+    ///
+    pub fn validate(&self) -> MLResult<()> {
+        if self.data.rows() != self.target.size() {
+            return Err(Error::new(
+                ErrorKind::InvalidState,
+                format!(
+                    "Number of rows ({}) does not match number of target values ({})",
+                    self.data.rows(),
+                    self.target.size()
+                ),
+            ));
+        }
+        if self.data.cols() != self.data_columns.size() {
+            return Err(Error::new(
+                ErrorKind::InvalidState,
+                format!(
+                    "Number of feature columns ({}) does not match number of column names ({})",
+                    self.data.cols(),
+                    self.data_columns.size()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks whether any feature value is NaN or infinite.
+    pub fn has_non_finite(&self) -> bool {
+        self.data.iter().any(|v| !v.is_finite())
+    }
+
+    /// Finds the positions of all NaN or infinite feature values, which can
+    /// sneak in through "NaN" strings in a CSV or overflowing arithmetic.
+    ///
+    /// #### Returns:
+    /// - The `(row, column)` positions of the non-finite values, in row major
+    ///   order.
+    ///
+    pub fn non_finite_locations(&self) -> Vec<(usize, usize)> {
+        let cols = self.data.cols();
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| !v.is_finite())
+            .map(|(idx, _)| (idx / cols, idx % cols))
+            .collect()
+    }
+
+    /// Returns references to the feature matrix and the target, in the usual
+    /// `(X, y)` order.
+    pub fn xy(&self) -> (&Matrix<f64>, &Vector<Y>) {
+        (&self.data, &self.target)
+    }
+
+    /// Consumes the dataset and returns the feature matrix and the target, in
+    /// the usual `(X, y)` order.
+    pub fn xy_owned(self) -> (Matrix<f64>, Vector<Y>) {
+        (self.data, self.target)
+    }
+
+    /// Returns the target values as a slice, without copying them.
+    pub fn target_slice(&self) -> &[Y] {
+        self.target.data()
+    }
+
+    /// Finds the features whose values barely vary.
+    ///
+    /// #### Parameters:
+    /// - tol: The largest range (max - min) a feature can have to be considered constant.
+    ///
+    /// #### Returns:
+    /// - The names of the constant features, in column order.
+    ///
+    pub fn constant_feature_names(&self, tol: f64) -> Vec<String> {
+        (0..self.data.cols())
+            .filter(|&idx| {
+                let column = self.data.col(idx);
+                let (min, max) = column
+                    .iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                        (min.min(v), max.max(v))
+                    });
+                max - min <= tol
+            })
+            .map(|idx| self.data_columns[idx].clone())
+            .collect()
+    }
+
+    /// Computes the variance inflation factor of each feature by regressing it
+    /// on all the other features. Values well above 10 usually point to
+    /// multicollinearity.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped list of `(feature name, VIF)` pairs in column order.
+    ///   Features that are a perfect linear combination of the others (or
+    ///   constant) have a VIF of `f64::INFINITY`.
+    ///
+    pub fn vif(&self) -> MLResult<Vec<(String, f64)>> {
+        if self.data.rows() < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "At least two rows are needed to compute variance inflation factors",
+            ));
+        }
+
+        let num_features = self.data.cols();
+        let mut factors = Vec::with_capacity(num_features);
+        for idx in 0..num_features {
+            let others: Vec<usize> = (0..num_features).filter(|&i| i != idx).collect();
+            let r_squared = ols_r_squared(
+                &self.data.select_cols(&others),
+                &Vector::new(self.data.col(idx).iter().cloned().collect::<Vec<f64>>()),
+            );
+            let factor = if r_squared.is_nan() || r_squared >= 1.0 - 1e-12 {
+                f64::INFINITY
+            } else {
+                1.0 / (1.0 - r_squared)
+            };
+            factors.push((self.data_columns[idx].clone(), factor));
+        }
+        Ok(factors)
+    }
+
+    /// Computes the `q`-th quantile of a feature using linear interpolation
+    /// between the closest ranks.
+    ///
+    /// #### Parameters:
+    /// - name: The feature column name.
+    /// - q: The quantile to compute, in the range [0, 1].
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped quantile value.
+    ///
+    pub fn quantile(&self, name: &str, q: f64) -> MLResult<f64> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!("Quantile must be in the range [0, 1], got {}", q),
+            ));
+        }
+        let col_index = self.column_index(name)?;
+        if self.data.rows() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot compute a quantile of an empty dataset",
+            ));
+        }
+        let sorted = sorted_values(self.data.col(col_index).iter());
+        Ok(sorted_quantile(&sorted, q))
+    }
+
+    /// Counts the values of a feature in uniformly wide bins spanning its
+    /// minimum and maximum. A constant feature is given a range of one around
+    /// its value, so all of its values land in a single bin.
+    ///
+    /// #### Parameters:
+    /// - name: The feature column name.
+    /// - bins: The number of bins.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped tuple of the `bins + 1` bin edges and the `bins`
+    ///   counts.
+    ///
+    pub fn histogram(&self, name: &str, bins: usize) -> MLResult<(Vec<f64>, Vec<usize>)> {
+        let (edges, bin_of_row) = self.uniform_bins(name, bins)?;
+        let mut counts = vec![0; bins];
+        for bin in bin_of_row {
+            counts[bin] += 1;
+        }
+        Ok((edges, counts))
+    }
+
+    /// Helper function that splits the range of a feature into uniformly wide
+    /// bins, as described for `histogram`, and finds the bin of every row.
+    ///
+    /// #### Parameters:
+    /// - name: The feature column name.
+    /// - bins: The number of bins.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped tuple of the `bins + 1` bin edges and the bin index
+    ///   of each row.
+    ///
+    fn uniform_bins(&self, name: &str, bins: usize) -> MLResult<(Vec<f64>, Vec<usize>)> {
+        if bins == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                "Number of bins must be at least 1",
+            ));
+        }
+        if self.data.rows() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot bin the values of an empty dataset",
+            ));
+        }
+        let column = self.data.col(self.column_index(name)?);
+        let (mut min, mut max) = column
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            });
+        if min == max {
+            min -= 0.5;
+            max += 0.5;
+        }
+
+        let width = (max - min) / bins as f64;
+        let edges = (0..=bins).map(|i| min + width * i as f64).collect();
+        let bin_of_row = column
+            .iter()
+            .map(|&value| (((value - min) / width) as usize).min(bins - 1))
+            .collect();
+        Ok((edges, bin_of_row))
+    }
+
+    /// Finds groups of rows with identical feature values.
+    ///
+    /// #### Returns:
+    /// - The row indices of each group of duplicated rows, ordered by their first
+    ///   occurrence. Rows without a duplicate are not included.
+    ///
+    pub fn duplicate_row_indices(&self) -> Vec<Vec<usize>> {
+        let mut positions: HashMap<Vec<u64>, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+
+        for (idx, row) in self.data.row_iter().enumerate() {
+            // Adding 0.0 folds -0.0 into 0.0 so the bit patterns match f64 equality.
+            let key: Vec<u64> = row.iter().map(|&v| (v + 0.0).to_bits()).collect();
+            match positions.get(&key) {
+                Some(&position) => groups[position].push(idx),
+                None => {
+                    positions.insert(key, groups.len());
+                    groups.push(vec![idx]);
+                }
+            }
+        }
+        groups.retain(|group| group.len() > 1);
+        groups
+    }
+
+    /// Drops rows with duplicated feature values, keeping the first occurrence.
+    ///
+    /// #### Returns:
+    /// - A new Dataset without the duplicated rows.
+    ///
+    pub fn drop_duplicate_rows(&self) -> Self {
+        let mut dropped = vec![false; self.data.rows()];
+        for group in self.duplicate_row_indices() {
+            for &idx in &group[1..] {
+                dropped[idx] = true;
+            }
+        }
+        let kept: Vec<usize> = (0..self.data.rows()).filter(|&i| !dropped[i]).collect();
+        self.select_rows(&kept)
+    }
+
+    /// Rebuilds the dataset with the feature columns in the given order.
+    ///
+    /// #### Parameters:
+    /// - order: The column names in their new order, must be a permutation of
+    ///   the existing data columns.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset with the reordered columns.
+    ///
+    pub fn reorder_columns(&self, order: &[&str]) -> MLResult<Self> {
+        if order.len() != self.data_columns.size() {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!(
+                    "Column order has {} names but the dataset has {} columns",
+                    order.len(),
+                    self.data_columns.size()
+                ),
+            ));
+        }
+        let mut indices = Vec::with_capacity(order.len());
+        for name in order {
+            let idx = self.column_index(name)?;
+            if indices.contains(&idx) {
+                return Err(Error::new(
+                    ErrorKind::InvalidParameters,
+                    format!("Column {} is repeated in the column order", name),
+                ));
+            }
+            indices.push(idx);
+        }
+
+        Ok(Dataset::new(
+            self.data.select_cols(&indices),
+            self.target.clone(),
+            Vector::new(order.iter().map(|s| s.to_string()).collect::<Vec<String>>()),
+            self.target_column.clone(),
+        ))
+    }
+
+    /// Computes the skewness (third standardized moment) of each feature.
+    /// Constant features have a skewness of 0.0.
+    ///
+    /// #### Returns:
+    /// - The skewness of each feature, in column order.
+    ///
+    pub fn skewness(&self) -> Vec<f64> {
+        self.standardized_moments(3)
+    }
+
+    /// Computes the kurtosis (fourth standardized moment, not the excess
+    /// kurtosis) of each feature. Constant features have a kurtosis of 0.0.
+    ///
+    /// #### Returns:
+    /// - The kurtosis of each feature, in column order.
+    ///
+    pub fn kurtosis(&self) -> Vec<f64> {
+        self.standardized_moments(4)
+    }
+
+    /// Standardizes each feature to zero mean and unit (population) standard
+    /// deviation in one call, without fitting a scaler. Constant features are
+    /// mapped to 0.0.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset with the standardized features, or an
+    ///   InvalidData error if the dataset is empty.
+    ///
+    pub fn zscore(&self) -> MLResult<Self> {
+        if self.data.rows() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot standardize an empty dataset",
+            ));
+        }
+        let stats: Vec<Option<(f64, f64)>> = (0..self.data.cols())
+            .map(|idx| {
+                self.column_mean_variance(idx)
+                    .map(|(mean, variance)| (mean, variance.sqrt()))
+            })
+            .collect();
+
+        let mut data = Vec::with_capacity(self.data.rows() * self.data.cols());
+        for row in self.data.row_iter() {
+            for (value, stat) in row.raw_slice().iter().zip(stats.iter()) {
+                data.push(match stat {
+                    Some((mean, std)) => (value - mean) / std,
+                    None => 0.0,
+                });
+            }
+        }
+
+        Ok(Dataset::new(
+            Matrix::new(self.data.rows(), self.data.cols(), data),
+            self.target.clone(),
+            self.data_columns.clone(),
+            self.target_column.clone(),
+        ))
+    }
+
+    /// Computes the given population standardized moment of each feature.
+    fn standardized_moments(&self, order: i32) -> Vec<f64> {
+        let num_rows = self.data.rows() as f64;
+        (0..self.data.cols())
+            .map(|idx| match self.column_mean_variance(idx) {
+                Some((mean, variance)) if !variance.is_nan() => {
+                    let column = self.data.col(idx);
+                    let moment =
+                        column.iter().map(|v| (v - mean).powi(order)).sum::<f64>() / num_rows;
+                    moment / variance.powf(order as f64 / 2.0)
+                }
+                _ => 0.0,
+            })
+            .collect()
+    }
+
+    /// Computes the mean and population variance of a feature column.
+    ///
+    /// #### Parameters:
+    /// - idx: The index of the feature column.
+    ///
+    /// #### Returns:
+    /// - The mean and variance, or None if the column is empty or constant.
+    ///   Constant columns are detected by comparing the values directly, as
+    ///   rounding can leave a small non-zero variance (e.g. for all 0.1).
+    ///
+    fn column_mean_variance(&self, idx: usize) -> Option<(f64, f64)> {
+        let column = self.data.col(idx);
+        let first = *column.iter().next()?;
+        if column.iter().all(|&v| v == first) {
+            return None;
+        }
+        let num_rows = self.data.rows() as f64;
+        let mean = column.iter().sum::<f64>() / num_rows;
+        let variance = column.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / num_rows;
+        Some((mean, variance))
+    }
+
+    /// Looks up the index of a feature column by name.
+    fn column_index(&self, name: &str) -> MLResult<usize> {
+        self.data_columns
+            .iter()
+            .position(|c| c == name)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidParameters,
+                    format!("Column {} not found in dataset", name),
+                )
+            })
+    }
+}
+
+impl<Y> Dataset<Matrix<f64>, Vector<Y>>
+where
+    Y: Clone + Debug + Eq + Hash,
+{
+    /// Samples a balanced subset of the dataset by drawing exactly `per_class`
+    /// rows (without replacement) from each class in the target.
+    ///
+    /// #### Parameters:
+    /// - per_class: The number of rows to draw from each class.
+    /// - seed: The seed for the random number generator.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset with `per_class` rows for each class, grouped
+    ///   by class in the order the classes first appear.
+    ///
+    pub fn balanced_sample(&self, per_class: usize, seed: u64) -> MLResult<Self> {
+        let mut rng = seeded_rng(seed);
+        let mut sampled_indices = Vec::new();
+
+        for (class, mut indices) in self.class_indices() {
+            if indices.len() < per_class {
+                return Err(Error::new(
+                    ErrorKind::InvalidParameters,
+                    format!(
+                        "Class {:?} has {} rows, cannot sample {} rows per class",
+                        class,
+                        indices.len(),
+                        per_class
+                    ),
+                ));
+            }
+            indices.shuffle(&mut rng);
+            sampled_indices.extend_from_slice(&indices[..per_class]);
+        }
+
+        Ok(self.select_rows(&sampled_indices))
+    }
+
+    /// Computes the per-feature mean of each target class.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped map from each class to its feature mean vector.
+    ///
+    pub fn group_means(&self) -> MLResult<HashMap<Y, Vector<f64>>> {
+        if self.data.rows() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot compute group means of an empty dataset",
+            ));
+        }
+        let mut means = HashMap::new();
+        for (class, indices) in self.class_indices() {
+            let class_rows = self.data.select_rows(&indices);
+            means.insert(class, class_rows.mean(Axes::Row));
+        }
+        Ok(means)
+    }
+
+    /// Flags features that perfectly determine the target, i.e. every distinct
+    /// value of the feature occurs with a single class only. This usually
+    /// points to target leakage, such as an ID-like column or an encoded copy
+    /// of the target left among the features.
+    ///
+    /// #### Returns:
+    /// - The names of the flagged features, in column order.
+    ///
+    pub fn leakage_candidates(&self) -> Vec<String> {
+        (0..self.data.cols())
+            .filter(|&idx| {
+                let mut class_of_value: HashMap<u64, &Y> = HashMap::new();
+                self.data
+                    .col(idx)
+                    .iter()
+                    .zip(self.target.iter())
+                    .all(|(value, class)| {
+                        // Adding 0.0 folds -0.0 into 0.0 so both share a key.
+                        let key = (value + 0.0).to_bits();
+                        *class_of_value.entry(key).or_insert(class) == class
+                    })
+            })
+            .map(|idx| self.data_columns[idx].clone())
+            .collect()
+    }
+
+    /// Computes the pooled within-class scatter matrix, the sum over the
+    /// target classes of each class's scatter around its own mean. It is the
+    /// within-class part of linear discriminant analysis.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped features x features scatter matrix, or an
+    ///   InvalidData error if the dataset is empty.
+    ///
+    pub fn within_class_scatter(&self) -> MLResult<Matrix<f64>> {
+        if self.data.rows() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot compute the scatter matrix of an empty dataset",
+            ));
+        }
+        let num_features = self.data.cols();
+        let mut scatter = Matrix::zeros(num_features, num_features);
+        for (_, indices) in self.class_indices() {
+            let class_rows = self.data.select_rows(&indices);
+            let class_mean = class_rows.mean(Axes::Row);
+            let centered = &class_rows
+                - Matrix::new(
+                    indices.len(),
+                    num_features,
+                    class_mean.data().repeat(indices.len()),
+                );
+            scatter += centered.transpose() * &centered;
+        }
+        Ok(scatter)
+    }
+
+    /// Returns the number of distinct target classes.
+    pub fn n_classes(&self) -> usize {
+        self.target.iter().collect::<HashSet<&Y>>().len()
+    }
+
+    /// Groups the row indices by their target class, keeping the classes in
+    /// the order they first appear.
+    pub(crate) fn class_indices(&self) -> Vec<(Y, Vec<usize>)> {
+        let mut positions: HashMap<&Y, usize> = HashMap::new();
+        let mut groups: Vec<(Y, Vec<usize>)> = Vec::new();
+
+        for (idx, label) in self.target.iter().enumerate() {
+            match positions.get(label) {
+                Some(&position) => groups[position].1.push(idx),
+                None => {
+                    positions.insert(label, groups.len());
+                    groups.push((label.clone(), vec![idx]));
+                }
+            }
+        }
+        groups
+    }
+}
+
+impl<Y> Dataset<Matrix<f64>, Vector<Y>>
+where
+    Y: Clone + Debug + Eq + Hash + Ord,
+{
+    /// Computes the centroid (per-feature mean) of each target class.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped tuple of the sorted class labels and a matrix whose
+    ///   rows are the matching class centroids.
+    ///
+    pub fn class_centroids(&self) -> MLResult<(Vec<Y>, Matrix<f64>)> {
+        let mut means: Vec<(Y, Vector<f64>)> = self.group_means()?.into_iter().collect();
+        means.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let num_classes = means.len();
+        let mut classes = Vec::with_capacity(num_classes);
+        let mut centroids = Vec::with_capacity(num_classes * self.data.cols());
+        for (class, mean) in means {
+            classes.push(class);
+            centroids.extend(mean.into_vec());
+        }
+        Ok((
+            classes,
+            Matrix::new(num_classes, self.data.cols(), centroids),
+        ))
+    }
+
+    /// Computes the sample covariance matrix of the features within each
+    /// target class, e.g. for quadratic discriminant analysis.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped tuple of the sorted class labels and the matching
+    ///   feature by feature covariance matrices, or an InvalidData error if a
+    ///   class has a single sample.
+    ///
+    pub fn class_covariances(&self) -> MLResult<(Vec<Y>, Vec<Matrix<f64>>)> {
+        let mut groups = self.class_indices();
+        groups.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let num_features = self.data.cols();
+        let mut classes = Vec::with_capacity(groups.len());
+        let mut covariances = Vec::with_capacity(groups.len());
+        for (class, indices) in groups {
+            if indices.len() < 2 {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "Class {:?} has a single sample, its covariance is undefined",
+                        class
+                    ),
+                ));
+            }
+            let class_data = self.data.select_rows(&indices);
+            let class_mean = class_data.mean(Axes::Row);
+            let mut centered = Vec::with_capacity(indices.len() * num_features);
+            for row in class_data.row_iter() {
+                centered.extend(row.iter().zip(class_mean.iter()).map(|(v, m)| v - m));
+            }
+            let centered = Matrix::new(indices.len(), num_features, centered);
+            covariances.push(centered.transpose() * &centered / (indices.len() - 1) as f64);
+            classes.push(class);
+        }
+        Ok((classes, covariances))
+    }
+}
+
+impl Dataset<Matrix<f64>, Vector<String>> {
+    /// Estimates the memory footprint of the dataset in bytes, counting the
+    /// feature values, the column names and the length of each target label.
+    pub fn memory_bytes(&self) -> usize {
+        let target_bytes: usize = self.target.iter().map(|label| label.len()).sum();
+        self.feature_memory_bytes() + target_bytes
+    }
+
+    /// Converts the string target into a binary one-vs-all target.
+    ///
+    /// #### Parameters:
+    /// - positive_class: The label that is mapped to 1.0, all other labels
+    ///   are mapped to 0.0.
+    ///
+    /// #### Returns:
+    /// - Dataset with the binary target.
+    ///
+    pub fn binarize_target(&self, positive_class: &str) -> Dataset<Matrix<f64>, Vector<f64>> {
+        Dataset::new(
+            self.data.clone(),
+            Vector::new(
+                self.target
+                    .iter()
+                    .map(|label| if label == positive_class { 1.0 } else { 0.0 })
+                    .collect::<Vec<f64>>(),
+            ),
+            self.data_columns.clone(),
+            self.target_column.clone(),
+        )
+    }
+
+    /// Label encodes the string target, consuming the dataset so the feature
+    /// matrix is moved rather than copied. Codes are assigned in the order the
+    /// labels first appear, the same as the `LabelEncoder`. Encoding changes
+    /// the target type from String to f64, which is why there is no in place
+    /// version on `&mut self`.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped tuple of the Dataset with the numeric target and the
+    ///   label mapping used.
+    ///
+    pub fn encode_target(self) -> MLResult<EncodedDataset> {
+        let (dataset, label_encoder) =
+            LabelEncoderFitter::<String, f64>::default().fit_transform_dataset(self)?;
+        Ok((dataset, label_encoder.fitter().label_map().clone()))
+    }
+
+    /// Encodes the string target with codes that follow an explicit order, the
+    /// first value in `order` gets 0, the next 1, and so on.
+    ///
+    /// #### Parameters:
+    /// - order: The target values from lowest to highest.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped tuple of the Dataset with the numeric target and the
+    ///   label mapping used, or an InvalidData error if a target value is not
+    ///   in `order`.
+    ///
+    pub fn encode_ordinal_target(&self, order: &[&str]) -> MLResult<EncodedDataset> {
+        let label_map: HashMap<String, f64> = order
+            .iter()
+            .enumerate()
+            .map(|(code, &label)| (label.to_string(), code as f64))
+            .collect();
+        let encoded_target = self
+            .target
+            .iter()
+            .map(|label| {
+                label_map.get(label).copied().ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("Target value {} is not in the provided order", label),
+                    )
+                })
+            })
+            .collect::<MLResult<Vec<f64>>>()?;
+
+        Ok((
+            Dataset::new(
+                self.data.clone(),
+                Vector::new(encoded_target),
+                self.data_columns.clone(),
+                self.target_column.clone(),
+            ),
+            label_map,
+        ))
+    }
+}
+
+impl Dataset<Matrix<f64>, Vector<f64>> {
+    /// Estimates the memory footprint of the dataset in bytes, counting the
+    /// feature values, the column names and the target values.
+    pub fn memory_bytes(&self) -> usize {
+        self.feature_memory_bytes() + self.target.size() * std::mem::size_of::<f64>()
+    }
+
+    /// Creates a Dataset from a CSV file with a string target column that is
+    /// label encoded while loading. Codes are assigned in the order the labels
+    /// first appear in the file.
+    ///
+    /// #### Parameters:
+    /// - filepath: A Path reference.
+    /// - target_column: The target column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped tuple of the loaded Dataset and the label mapping used.
+    ///
+    pub fn from_csv_encoded<P: AsRef<Path>>(
+        file_path: P,
+        target_column: &str,
+    ) -> MLResult<EncodedDataset> {
+        Dataset::<Matrix<f64>, Vector<String>>::from_csv(file_path, target_column)?.encode_target()
+    }
+
+    /// Combines the features and the target into a single matrix.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped matrix with the feature columns followed by the
+    ///   target as the last column.
+    ///
+    pub fn to_full_matrix(&self) -> MLResult<Matrix<f64>> {
+        if self.data.rows() != self.target.size() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Number of rows ({}) does not match number of target values ({})",
+                    self.data.rows(),
+                    self.target.size()
+                ),
+            ));
+        }
+        let target = Matrix::new(self.target.size(), 1, self.target.data().clone());
+        Ok(self.data.hcat(&target))
+    }
+
+    /// Bins a feature into uniformly wide bins, as `histogram` does, and
+    /// computes the mean target of each bin. This gives a quick look at how
+    /// the target changes with the feature.
+    ///
+    /// #### Parameters:
+    /// - feature: The feature column name.
+    /// - bins: The number of bins.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped list of `(bin center, mean target)` pairs in
+    ///   ascending bin order. Empty bins are omitted.
+    ///
+    pub fn target_by_bin(&self, feature: &str, bins: usize) -> MLResult<Vec<(f64, f64)>> {
+        let (edges, bin_of_row) = self.uniform_bins(feature, bins)?;
+        let mut sums = vec![0.0; bins];
+        let mut counts = vec![0; bins];
+        for (bin, target) in bin_of_row.into_iter().zip(self.target.iter()) {
+            sums[bin] += target;
+            counts[bin] += 1;
+        }
+
+        Ok((0..bins)
+            .filter(|&bin| counts[bin] > 0)
+            .map(|bin| {
+                (
+                    (edges[bin] + edges[bin + 1]) / 2.0,
+                    sums[bin] / counts[bin] as f64,
+                )
+            })
+            .collect())
+    }
+
+    /// Computes the leverage of each sample, the diagonal of the hat matrix
+    /// `H = X(XᵀX)⁻¹Xᵀ` of a linear regression with an intercept. Samples
+    /// with a high leverage have an unusual combination of feature values and
+    /// can strongly influence the fit.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped vector of leverage values, one for each sample, or a
+    ///   LinAlgError if `XᵀX` is singular.
+    ///
+    pub fn leverage(&self) -> MLResult<Vector<f64>> {
+        let x = Matrix::ones(self.data.rows(), 1).hcat(&self.data);
+        let (values, vectors) = symmetric_eigendecomp(&(x.transpose() * &x));
+        let tolerance = values.iter().cloned().fold(0.0, f64::max) * 1e-10;
+        if values.iter().any(|&v| v <= tolerance) {
+            return Err(Error::new(
+                ErrorKind::LinAlgError,
+                "XᵀX is singular, the features (with intercept) are linearly dependent",
+            ));
+        }
+        let inverse_values: Vec<f64> = values.iter().map(|v| 1.0 / v).collect();
+        let inverse = &vectors * Matrix::from_diag(&inverse_values) * vectors.transpose();
+
+        // The i-th diagonal element of H is xᵢᵀ(XᵀX)⁻¹xᵢ.
+        let projected = &x * inverse;
+        Ok(Vector::new(
+            x.row_iter()
+                .zip(projected.row_iter())
+                .map(|(row, projected_row)| {
+                    row.iter()
+                        .zip(projected_row.iter())
+                        .map(|(a, b)| a * b)
+                        .sum::<f64>()
+                })
+                .collect::<Vec<f64>>(),
+        ))
+    }
+}
+
+/// Builder for a numeric Dataset, an alternative to the positional
+/// `Dataset::new` constructor that checks the pieces fit together.
+///
+/// ## Examples
+/// ```
+/// use rust_ml::dataset::DatasetBuilder;
+/// use rust_ml::linalg::{BaseMatrix, Matrix, Vector};
+///
+/// let dataset = DatasetBuilder::new()
+///     .features(Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]))
+///     .target(Vector::new(vec![0, 1]))
+///     .columns(vec!["a".to_string(), "b".to_string()])
+///     .target_name("label".to_string())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(2, dataset.data().rows());
+/// ```
+#[derive(Clone, Debug)]
+pub struct DatasetBuilder<Y>
+where
+    Y: Clone + Debug,
+{
+    /// The feature matrix.
+    features: Option<Matrix<f64>>,
+    /// The label vector.
+    target: Option<Vector<Y>>,
+    /// The data column headers.
+    columns: Option<Vec<String>>,
+    /// The target (label) column header.
+    target_name: Option<String>,
+}
+
+impl<Y> DatasetBuilder<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        DatasetBuilder {
+            features: None,
+            target: None,
+            columns: None,
+            target_name: None,
+        }
+    }
+
+    /// Sets the feature matrix.
+    pub fn features(mut self, features: Matrix<f64>) -> Self {
+        self.features = Some(features);
+        self
+    }
+
+    /// Sets the target vector.
+    pub fn target(mut self, target: Vector<Y>) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// Sets the data column headers.
+    pub fn columns(mut self, columns: Vec<String>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Sets the target column header.
+    pub fn target_name(mut self, target_name: String) -> Self {
+        self.target_name = Some(target_name);
+        self
+    }
+
+    /// Builds the Dataset.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset, an InvalidParameters error if a field was not
+    ///   set, or an InvalidData error if the dimensions do not agree.
+    ///
+    pub fn build(self) -> MLResult<Dataset<Matrix<f64>, Vector<Y>>> {
+        let missing = |field: &str| {
+            Error::new(
+                ErrorKind::InvalidParameters,
+                format!("Dataset builder is missing the {}", field),
+            )
+        };
+        let features = self.features.ok_or_else(|| missing("features"))?;
+        let target = self.target.ok_or_else(|| missing("target"))?;
+        let columns = self.columns.ok_or_else(|| missing("columns"))?;
+        let target_name = self.target_name.ok_or_else(|| missing("target name"))?;
+
+        if features.rows() != target.size() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Number of rows ({}) does not match number of target values ({})",
+                    features.rows(),
+                    target.size()
+                ),
+            ));
+        }
+        if features.cols() != columns.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Number of feature columns ({}) does not match number of column names ({})",
+                    features.cols(),
+                    columns.len()
+                ),
+            ));
+        }
+
+        Ok(Dataset::new(
+            features,
+            target,
+            Vector::new(columns),
+            target_name,
+        ))
+    }
+}
+
+impl<Y> Default for DatasetBuilder<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Creates an empty builder.
+    fn default() -> Self {
+        DatasetBuilder::new()
+    }
+}
+
+/// Struct for a dataset with multiple target columns.
+#[derive(Clone, Debug)]
+pub struct MultiTargetDataset<X>
+where
+    X: Clone + Debug,
+{
+    /// The feature matrix.
+    data: X,
+    /// The target matrix, one column per target.
+    target: X,
+    /// The data column headers (not including the target column headers).
+    data_columns: Vector<String>,
+    /// The target column headers.
+    target_columns: Vector<String>,
+}
+
+/// Constructor and some getters for the MultiTargetDataset struct.
+impl<X> MultiTargetDataset<X>
+where
+    X: Clone + Debug,
+{
+    /// Constructor.
+    pub fn new(
+        data: X,
+        target: X,
+        data_columns: Vector<String>,
+        target_columns: Vector<String>,
+    ) -> Self {
+        MultiTargetDataset {
+            data,
+            target,
+            data_columns,
+            target_columns,
+        }
+    }
+
+    /// Returns a reference to the features value.
+    pub fn data(&self) -> &X {
+        &self.data
+    }
+
+    /// Returns a reference to the target matrix.
+    pub fn target(&self) -> &X {
+        &self.target
+    }
+
+    /// Returns a reference to the data_columns vector.
+    pub fn data_columns(&self) -> &Vector<String> {
+        &self.data_columns
+    }
+
+    /// Returns a reference to the target_columns vector.
+    pub fn target_columns(&self) -> &Vector<String> {
+        &self.target_columns
+    }
+}
+
+impl<X> MultiTargetDataset<Matrix<X>>
+where
+    X: Float + Debug + FromStr,
+{
+    /// Creates a MultiTargetDataset struct from a CSV file. All feature and target
+    /// columns have to be of the same, numeric type.
+    ///
+    /// #### Parameters:
+    /// - filepath: A Path reference.
+    /// - target_columns: The target column names.
+    ///
+    /// #### Returns:
+    /// - The loaded dataset in an MLResult instance.
+    ///
+    pub fn from_csv<P: AsRef<Path>>(file_path: P, target_columns: &[&str]) -> MLResult<Self> {
+        let file = File::open(file_path).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        Self::from_reader(file, target_columns)
+    }
+
+    /// Creates a MultiTargetDataset struct from CSV data held in a string,
+    /// parsed the same as `from_csv`.
+    ///
+    /// #### Parameters:
+    /// - data: The CSV data, including the header row.
+    /// - target_columns: The target column names.
+    ///
+    /// #### Returns:
+    /// - The loaded dataset in an MLResult instance.
+    ///
+    pub fn from_csv_str(data: &str, target_columns: &[&str]) -> MLResult<Self> {
+        Self::from_reader(data.as_bytes(), target_columns)
+    }
+
+    /// Helper function that parses the CSV data from any reader into a
+    /// MultiTargetDataset.
+    ///
+    /// #### Parameters:
+    /// - reader: The source of the CSV data.
+    /// - target_columns: The target column names.
+    ///
+    /// #### Returns:
+    /// - The loaded dataset in an MLResult instance.
+    ///
+    fn from_reader<R: Read>(reader: R, target_columns: &[&str]) -> MLResult<Self> {
+        if target_columns.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                "At least one target column is required.",
+            ));
+        }
+        // Create the csv reader (assumes headers are available).
+        let mut rdr = ReaderBuilder::new().has_headers(true).from_reader(reader);
+
+        let (headers, target_idxs) = process_target_headers(&mut rdr, target_columns)?;
+
+        let mut data_values = Vec::new();
+        let mut target_values = Vec::new();
+        let mut num_rows = 0;
+        for record_result in rdr.records() {
+            let record = record_result.map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            let mut record_targets = vec![X::zero(); target_idxs.len()];
+            for (index, feature) in record.iter().enumerate() {
+                let value = parse_value(feature, index)?;
+                match target_idxs.iter().position(|&t| t == index) {
+                    Some(target_position) => record_targets[target_position] = value,
+                    None => data_values.push(value),
                 }
             }
-            data_rows.push(record_features);
+            target_values.extend(record_targets);
+            num_rows += 1;
         }
-        let row_dim = data_rows.len();
-        let col_dim = data_rows[0].len();
 
-        // Convert the accumulated data into a matrix for the dataset struct.
-        let flattened_data: Vec<X> = data_rows.into_iter().flatten().collect();
-        let data = Matrix::new(row_dim, col_dim, flattened_data);
+        let data_columns: Vec<String> = headers
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !target_idxs.contains(idx))
+            .map(|(_, h)| h.to_string())
+            .collect();
 
-        Ok(Dataset::new(
-            data,
-            Vector::new(target_values),
+        Ok(MultiTargetDataset::new(
+            Matrix::new(num_rows, data_columns.len(), data_values),
+            Matrix::new(num_rows, target_idxs.len(), target_values),
+            Vector::new(data_columns),
             Vector::new(
-                headers
+                target_columns
                     .iter()
-                    .filter(|&h| h != target_column)
                     .map(|s| s.to_string())
                     .collect::<Vec<String>>(),
             ),
-            String::from(target_column),
         ))
     }
 }
 
-/// Can represent a numeric or categorical data value.
+/// Can represent a numeric or categorical data value, or a missing one.
 #[derive(Debug, Clone, PartialEq)]
 pub enum MixedDataValue {
     /// Numeric data values are f64s.
     Numeric(f64),
     /// Categorical data values are Strings.
     Categorical(String),
+    /// A missing value: a cell matching one of the `from_csv_with_na` markers
+    /// in any column, or an empty string re-typed by `set_column_numeric`.
+    Missing,
+}
+
+/// A column of values used to build a MixedDataset column by column.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+    /// A column of numeric values.
+    Numeric(Vec<f64>),
+    /// A column of categorical values.
+    Categorical(Vec<String>),
+}
+
+impl Column {
+    /// Returns the number of values in the column.
+    pub fn len(&self) -> usize {
+        match self {
+            Column::Numeric(values) => values.len(),
+            Column::Categorical(values) => values.len(),
+        }
+    }
+
+    /// Returns whether the column has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 /// Struct for a mixed value dataset. This struct can
@@ -251,6 +1570,8 @@ where
     /// - filepath: A Path reference.
     /// - target_column: The target column name.
     /// - numeric_columns: The columns that contain numeric values, other columns will be assumed categorical.
+    ///   Empty cells in numeric columns fail to parse, pass `""` as a marker to
+    ///   `from_csv_with_na` to read them as `MixedDataValue::Missing` instead.
     ///
     /// #### Returns:
     /// - The loaded dataset in an MLResult instance.
@@ -261,8 +1582,71 @@ where
         numeric_columns: &[&str],
     ) -> MLResult<Self> {
         let file = File::open(file_path).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
-        // Create the csv reader from the file (assumes headers are available).
-        let mut rdr = ReaderBuilder::new().has_headers(true).from_reader(file);
+        Self::from_reader(file, target_column, numeric_columns, &[])
+    }
+
+    /// Creates a MixedDataset struct from a CSV file like `from_csv`, but also
+    /// reads any feature cell that matches one of the given markers (e.g. "NA",
+    /// "null" or "?") as `MixedDataValue::Missing`, in numeric and categorical
+    /// columns alike. Passing `""` as a marker reads empty cells as missing.
+    ///
+    /// #### Parameters:
+    /// - filepath: A Path reference.
+    /// - target_column: The target column name.
+    /// - numeric_columns: The columns that contain numeric values, other columns will be assumed categorical.
+    /// - na_values: The cell values that mark a missing value.
+    ///
+    /// #### Returns:
+    /// - The loaded dataset in an MLResult instance.
+    ///
+    pub fn from_csv_with_na<P: AsRef<Path>>(
+        file_path: P,
+        target_column: &str,
+        numeric_columns: &[&str],
+        na_values: &[&str],
+    ) -> MLResult<Self> {
+        let file = File::open(file_path).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        Self::from_reader(file, target_column, numeric_columns, na_values)
+    }
+
+    /// Creates a MixedDataset struct from CSV data held in a string, parsed the
+    /// same as `from_csv`.
+    ///
+    /// #### Parameters:
+    /// - data: The CSV data, including the header row.
+    /// - target_column: The target column name.
+    /// - numeric_columns: The columns that contain numeric values, other columns will be assumed categorical.
+    ///
+    /// #### Returns:
+    /// - The loaded dataset in an MLResult instance.
+    ///
+    pub fn from_csv_str(
+        data: &str,
+        target_column: &str,
+        numeric_columns: &[&str],
+    ) -> MLResult<Self> {
+        Self::from_reader(data.as_bytes(), target_column, numeric_columns, &[])
+    }
+
+    /// Helper function that parses the CSV data from any reader into a MixedDataset.
+    ///
+    /// #### Parameters:
+    /// - reader: The source of the CSV data.
+    /// - target_column: The target column name.
+    /// - numeric_columns: The columns that contain numeric values.
+    /// - na_values: The feature cell values that are read as missing.
+    ///
+    /// #### Returns:
+    /// - The loaded dataset in an MLResult instance.
+    ///
+    fn from_reader<R: Read>(
+        reader: R,
+        target_column: &str,
+        numeric_columns: &[&str],
+        na_values: &[&str],
+    ) -> MLResult<Self> {
+        // Create the csv reader (assumes headers are available).
+        let mut rdr = ReaderBuilder::new().has_headers(true).from_reader(reader);
 
         let (headers, target_index) = process_headers(&mut rdr, target_column)?;
 
@@ -286,7 +1670,9 @@ where
             let record = record_result.map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
             let mut record_features = Vec::new();
             for (index, feature) in record.iter().enumerate() {
-                let data_value = if numeric_idxs.contains(&index) {
+                let data_value = if na_values.contains(&feature) {
+                    MixedDataValue::Missing
+                } else if numeric_idxs.contains(&index) {
                     MixedDataValue::Numeric(feature.parse::<f64>().map_err(|e| {
                         Error::new(
                             ErrorKind::InvalidData,
@@ -329,6 +1715,426 @@ where
     }
 }
 
+impl<Y> MixedDataset<Vector<Y>>
+where
+    Y: Clone + Debug,
+{
+    /// Builds a MixedDataset from column-wise data.
+    ///
+    /// #### Parameters:
+    /// - columns: The name and values of each feature column, in order.
+    /// - target: The target vector.
+    /// - target_column: The target column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped MixedDataset, or an InvalidData error if the columns
+    ///   and target do not all have the same length.
+    ///
+    pub fn from_columns(
+        columns: Vec<(String, Column)>,
+        target: Vector<Y>,
+        target_column: String,
+    ) -> MLResult<Self> {
+        let num_rows = target.size();
+        if let Some((name, column)) = columns.iter().find(|(_, c)| c.len() != num_rows) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Column {} has {} values but the target has {}",
+                    name,
+                    column.len(),
+                    num_rows
+                ),
+            ));
+        }
+
+        let mut data = vec![Vec::with_capacity(columns.len()); num_rows];
+        let mut data_columns = Vec::with_capacity(columns.len());
+        for (name, column) in columns {
+            match column {
+                Column::Numeric(values) => {
+                    for (row, value) in data.iter_mut().zip(values) {
+                        row.push(MixedDataValue::Numeric(value));
+                    }
+                }
+                Column::Categorical(values) => {
+                    for (row, value) in data.iter_mut().zip(values) {
+                        row.push(MixedDataValue::Categorical(value));
+                    }
+                }
+            }
+            data_columns.push(name);
+        }
+
+        Ok(MixedDataset::new(
+            data,
+            target,
+            Vector::new(data_columns),
+            target_column,
+        ))
+    }
+
+    /// Returns an iterator over the feature rows.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &Vec<MixedDataValue>> {
+        self.data.iter()
+    }
+
+    /// Fetches a single cell by row index and column name.
+    ///
+    /// #### Parameters:
+    /// - row: The row index.
+    /// - col: The column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped reference to the cell value.
+    ///
+    pub fn get(&self, row: usize, col: &str) -> MLResult<&MixedDataValue> {
+        let col_index = self.column_index(col)?;
+        self.data
+            .get(row)
+            .map(|values| &values[col_index])
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidParameters,
+                    format!(
+                        "Row {} is out of range for dataset with {} rows",
+                        row,
+                        self.data.len()
+                    ),
+                )
+            })
+    }
+
+    /// Returns a copy of the dataset with a categorical column appended.
+    ///
+    /// #### Parameters:
+    /// - name: The new column name.
+    /// - values: The value for each row.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped MixedDataset with the new column last.
+    ///
+    pub fn add_categorical_column(&self, name: &str, values: Vec<String>) -> MLResult<Self> {
+        self.add_column(
+            name,
+            values
+                .into_iter()
+                .map(MixedDataValue::Categorical)
+                .collect(),
+        )
+    }
+
+    /// Returns a copy of the dataset with a numeric column appended.
+    ///
+    /// #### Parameters:
+    /// - name: The new column name.
+    /// - values: The value for each row.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped MixedDataset with the new column last.
+    ///
+    pub fn add_numeric_column(&self, name: &str, values: Vec<f64>) -> MLResult<Self> {
+        self.add_column(
+            name,
+            values.into_iter().map(MixedDataValue::Numeric).collect(),
+        )
+    }
+
+    /// Returns the values of a numeric column.
+    ///
+    /// #### Parameters:
+    /// - name: The column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped column values, or an InvalidData error if the column
+    ///   holds categorical or missing values.
+    ///
+    pub fn numeric_column(&self, name: &str) -> MLResult<Vec<f64>> {
+        self.typed_column(name, "numeric", |value| match value {
+            MixedDataValue::Numeric(num) => Some(*num),
+            _ => None,
+        })
+    }
+
+    /// Returns the values of a categorical column.
+    ///
+    /// #### Parameters:
+    /// - name: The column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped column values, or an InvalidData error if the column
+    ///   holds numeric or missing values.
+    ///
+    pub fn categorical_column(&self, name: &str) -> MLResult<Vec<String>> {
+        self.typed_column(name, "categorical", |value| match value {
+            MixedDataValue::Categorical(category) => Some(category.clone()),
+            _ => None,
+        })
+    }
+
+    /// Collects the values of a column, erroring on the first value that the
+    /// `extract` function does not accept.
+    fn typed_column<T, F>(&self, name: &str, kind: &str, extract: F) -> MLResult<Vec<T>>
+    where
+        F: Fn(&MixedDataValue) -> Option<T>,
+    {
+        let col_index = self.column_index(name)?;
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(row_index, row)| {
+                extract(&row[col_index]).ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "Column {} is not {}, found {:?} in row {}",
+                            name, kind, row[col_index], row_index
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Computes the mean of a numeric column, skipping missing cells.
+    ///
+    /// #### Parameters:
+    /// - name: The column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped mean of the present values.
+    ///
+    pub fn column_mean_skip_missing(&self, name: &str) -> MLResult<f64> {
+        let values = self.present_values(name)?;
+        Ok(values.iter().sum::<f64>() / values.len() as f64)
+    }
+
+    /// Computes the minimum of a numeric column, skipping missing cells.
+    ///
+    /// #### Parameters:
+    /// - name: The column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped minimum of the present values.
+    ///
+    pub fn column_min_skip_missing(&self, name: &str) -> MLResult<f64> {
+        let values = self.present_values(name)?;
+        Ok(values.into_iter().fold(f64::INFINITY, f64::min))
+    }
+
+    /// Computes the maximum of a numeric column, skipping missing cells.
+    ///
+    /// #### Parameters:
+    /// - name: The column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped maximum of the present values.
+    ///
+    pub fn column_max_skip_missing(&self, name: &str) -> MLResult<f64> {
+        let values = self.present_values(name)?;
+        Ok(values.into_iter().fold(f64::NEG_INFINITY, f64::max))
+    }
+
+    /// Computes the population standard deviation of a numeric column,
+    /// skipping missing cells.
+    ///
+    /// #### Parameters:
+    /// - name: The column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped standard deviation of the present values.
+    ///
+    pub fn column_std_skip_missing(&self, name: &str) -> MLResult<f64> {
+        let values = self.present_values(name)?;
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+        Ok(variance.sqrt())
+    }
+
+    /// Splits the dataset into one dataset with the numeric columns and one
+    /// with the categorical columns, both sharing the target. A column counts
+    /// as categorical if any of its values is categorical.
+    ///
+    /// #### Returns:
+    /// - Tuple of the numeric and the categorical MixedDataset, with the
+    ///   columns in their original order.
+    ///
+    pub fn split_by_type(&self) -> (Self, Self) {
+        let (categorical, numeric): (Vec<usize>, Vec<usize>) = (0..self.data_columns.size())
+            .partition(|&idx| {
+                self.data
+                    .iter()
+                    .any(|row| matches!(row[idx], MixedDataValue::Categorical(_)))
+            });
+        (
+            self.select_columns(&numeric),
+            self.select_columns(&categorical),
+        )
+    }
+
+    /// Builds a new MixedDataset from the columns at the given indices.
+    fn select_columns(&self, indices: &[usize]) -> Self {
+        MixedDataset::new(
+            self.data
+                .iter()
+                .map(|row| indices.iter().map(|&idx| row[idx].clone()).collect())
+                .collect(),
+            self.target.clone(),
+            Vector::new(
+                indices
+                    .iter()
+                    .map(|&idx| self.data_columns[idx].clone())
+                    .collect::<Vec<String>>(),
+            ),
+            self.target_column.clone(),
+        )
+    }
+
+    /// Collects the non-missing values of a numeric column. Errors if the
+    /// column holds categorical values or has no values present.
+    fn present_values(&self, name: &str) -> MLResult<Vec<f64>> {
+        let col_index = self.column_index(name)?;
+        let mut values = Vec::with_capacity(self.data.len());
+        for row in &self.data {
+            match &row[col_index] {
+                MixedDataValue::Numeric(value) => values.push(*value),
+                MixedDataValue::Missing => {}
+                MixedDataValue::Categorical(_) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!("Column {} is not numeric", name),
+                    ))
+                }
+            }
+        }
+        if values.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Column {} has no values present", name),
+            ));
+        }
+        Ok(values)
+    }
+
+    /// Returns a copy of the dataset with the named column re-typed as numeric.
+    /// Categorical cells are parsed as f64, empty ones become missing values.
+    ///
+    /// #### Parameters:
+    /// - name: The column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped MixedDataset, or an InvalidData error if a cell can't
+    ///   be parsed.
+    ///
+    pub fn set_column_numeric(&self, name: &str) -> MLResult<Self> {
+        self.map_column(name, |value| match value {
+            MixedDataValue::Categorical(text) if text.is_empty() => Ok(MixedDataValue::Missing),
+            MixedDataValue::Categorical(text) => text
+                .parse::<f64>()
+                .map(MixedDataValue::Numeric)
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("Failed to parse value {} in column {}.\n{}", text, name, e),
+                    )
+                }),
+            other => Ok(other.clone()),
+        })
+    }
+
+    /// Returns a copy of the dataset with the named column re-typed as
+    /// categorical. Numeric cells are converted to their string representation
+    /// and missing values become empty strings.
+    ///
+    /// #### Parameters:
+    /// - name: The column name.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped MixedDataset.
+    ///
+    pub fn set_column_categorical(&self, name: &str) -> MLResult<Self> {
+        self.map_column(name, |value| match value {
+            MixedDataValue::Numeric(number) => Ok(MixedDataValue::Categorical(number.to_string())),
+            MixedDataValue::Missing => Ok(MixedDataValue::Categorical(String::new())),
+            other => Ok(other.clone()),
+        })
+    }
+
+    /// Applies a conversion to every cell of the named column of a copy of the
+    /// dataset.
+    fn map_column<F>(&self, name: &str, convert: F) -> MLResult<Self>
+    where
+        F: Fn(&MixedDataValue) -> MLResult<MixedDataValue>,
+    {
+        let col_index = self.column_index(name)?;
+        let mut data = self.data.clone();
+        for row in data.iter_mut() {
+            row[col_index] = convert(&row[col_index])?;
+        }
+
+        Ok(MixedDataset::new(
+            data,
+            self.target.clone(),
+            self.data_columns.clone(),
+            self.target_column.clone(),
+        ))
+    }
+
+    /// Appends a column of already wrapped values to a copy of the dataset.
+    fn add_column(&self, name: &str, values: Vec<MixedDataValue>) -> MLResult<Self> {
+        if values.len() != self.data.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Column {} has {} values but the dataset has {} rows",
+                    name,
+                    values.len(),
+                    self.data.len()
+                ),
+            ));
+        }
+        if self.data_columns.iter().any(|c| c == name) || self.target_column == name {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!("Column {} already exists in dataset", name),
+            ));
+        }
+
+        let data = self
+            .data
+            .iter()
+            .zip(values)
+            .map(|(row, value)| {
+                let mut row = row.clone();
+                row.push(value);
+                row
+            })
+            .collect();
+        let mut data_columns = self.data_columns.clone().into_vec();
+        data_columns.push(name.to_string());
+
+        Ok(MixedDataset::new(
+            data,
+            self.target.clone(),
+            Vector::new(data_columns),
+            self.target_column.clone(),
+        ))
+    }
+
+    /// Looks up the index of a feature column by name.
+    fn column_index(&self, name: &str) -> MLResult<usize> {
+        self.data_columns
+            .iter()
+            .position(|c| c == name)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidParameters,
+                    format!("Column {} not found in dataset", name),
+                )
+            })
+    }
+}
+
 /// Helper function that processes the headers in the CSV file and makes sure
 /// the user passed target column exists.
 ///
@@ -338,28 +2144,75 @@ where
 ///
 /// #### Returns:
 /// - A Result wrapped tuple containing the isolated header row and the target column
-/// index or an Error.
+///   index or an Error.
 ///
-fn process_headers<R: std::io::Read>(
+fn process_headers<R: Read>(
     rdr: &mut csv::Reader<R>,
     target_column: &str,
 ) -> Result<(csv::StringRecord, usize), Error> {
+    let (headers, target_idxs) = process_target_headers(rdr, &[target_column])?;
+    Ok((headers, target_idxs[0]))
+}
+
+/// Helper function that processes the headers in the CSV file and makes sure
+/// each of the user passed target columns exists and is only listed once.
+///
+/// #### Parameters:
+/// - rdr: The CSV Reader.
+/// - target_columns: The target column names.
+///
+/// #### Returns:
+/// - A Result wrapped tuple containing the isolated header row and the index
+///   of each target column, in the given order, or an Error.
+///
+fn process_target_headers<R: Read>(
+    rdr: &mut csv::Reader<R>,
+    target_columns: &[&str],
+) -> Result<(csv::StringRecord, Vec<usize>), Error> {
     // Isolate header row.
     let headers = rdr
         .headers()
         .map_err(|e| Error::new(ErrorKind::InvalidData, e))?
         .clone();
 
-    // Make sure the target column exists in the file column headers.
-    let target_index = headers
-        .iter()
-        .position(|h| h == target_column)
-        .ok_or_else(|| {
-            Error::new(
-                ErrorKind::InvalidData,
-                format!("Target column {} not found in CSV file.", target_column),
-            )
-        })?;
+    let mut target_idxs = Vec::with_capacity(target_columns.len());
+    for (position, target_column) in target_columns.iter().enumerate() {
+        if target_columns[..position].contains(target_column) {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!("Target column {} is listed more than once.", target_column),
+            ));
+        }
+        // Make sure the target column exists in the file column headers.
+        let target_index = headers
+            .iter()
+            .position(|h| h == *target_column)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Target column {} not found in CSV file.", target_column),
+                )
+            })?;
+        target_idxs.push(target_index);
+    }
+
+    Ok((headers, target_idxs))
+}
 
-    Ok((headers, target_index))
+/// Helper function that parses a single numeric CSV value.
+///
+/// #### Parameters:
+/// - value: The raw CSV value.
+/// - index: The index of the value's column, used in the error message.
+///
+/// #### Returns:
+/// - MLResult wrapped parsed value, or an InvalidData error.
+///
+fn parse_value<X: FromStr>(value: &str, index: usize) -> MLResult<X> {
+    X::from_str(value).map_err(|_| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("Failed to parse value {} in column {}", value, index),
+        )
+    })
 }
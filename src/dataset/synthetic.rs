@@ -0,0 +1,191 @@
+//! # Synthetic Datasets
+//!
+//! Generators for small synthetic datasets, so examples and tests don't need
+//! to depend on CSV files. All generators take a seed and produce the same
+//! dataset for the same seed. `meshgrid` builds a grid of 2D points to
+//! evaluate a model over, e.g. to plot its decision boundary.
+//!
+//! ## Examples
+//!
+//! ```
+//! use rust_ml::dataset::synthetic::make_blobs;
+//! use rust_ml::linalg::BaseMatrix;
+//!
+//! let blobs = make_blobs(100, 2, 3, 1.0, 42);
+//!
+//! assert_eq!(100, blobs.data().rows());
+//! assert_eq!(2, blobs.data().cols());
+//! ```
+
+use super::Dataset;
+use crate::base::rng::{seeded_rng, standard_normal};
+use crate::linalg::{Matrix, Vector};
+
+use rand::Rng;
+
+/// Type alias for a generated regression dataset along with the true
+/// coefficients used to build its target.
+pub type RegressionDataset = (Dataset<Matrix<f64>, Vector<f64>>, Vec<f64>);
+
+/// Generates isotropic Gaussian blobs for clustering and classification.
+/// The centers are drawn uniformly from `[-10, 10]` in every feature and the
+/// samples are split as evenly as possible between them. The features are
+/// named `feature_1`, `feature_2`, ... and the target is the `cluster` index.
+///
+/// #### Parameters:
+/// - n_samples: The total number of samples.
+/// - n_features: The number of features of each sample.
+/// - centers: The number of blobs.
+/// - cluster_std: The standard deviation of each blob.
+/// - seed: The seed for the random number generator.
+///
+/// #### Returns:
+/// - The generated dataset.
+///
+/// ## Panics
+///
+/// If `centers` is zero while `n_samples` is not.
+///
+pub fn make_blobs(
+    n_samples: usize,
+    n_features: usize,
+    centers: usize,
+    cluster_std: f64,
+    seed: u64,
+) -> Dataset<Matrix<f64>, Vector<usize>> {
+    assert!(
+        centers > 0 || n_samples == 0,
+        "make_blobs needs at least one center"
+    );
+    let mut rng = seeded_rng(seed);
+    let center_values: Vec<Vec<f64>> = (0..centers)
+        .map(|_| {
+            (0..n_features)
+                .map(|_| rng.gen_range(-10.0..=10.0))
+                .collect()
+        })
+        .collect();
+
+    let mut data = Vec::with_capacity(n_samples * n_features);
+    let mut target = Vec::with_capacity(n_samples);
+    for sample in 0..n_samples {
+        // Assigning samples round robin keeps the blob sizes within one of
+        // each other.
+        let cluster = sample % centers.max(1);
+        for &center in &center_values[cluster] {
+            data.push(center + cluster_std * standard_normal(&mut rng));
+        }
+        target.push(cluster);
+    }
+
+    Dataset::new(
+        Matrix::new(n_samples, n_features, data),
+        Vector::new(target),
+        feature_names(n_features),
+        "cluster".to_string(),
+    )
+}
+
+/// Generates a linear regression problem with standard normal features. The
+/// true coefficients are drawn uniformly from `[-10, 10]` and the target is
+/// the features' linear combination plus Gaussian noise. The features are
+/// named `feature_1`, `feature_2`, ... and the target is `target`.
+///
+/// #### Parameters:
+/// - n_samples: The number of samples.
+/// - n_features: The number of features of each sample.
+/// - noise: The standard deviation of the noise added to the target.
+/// - seed: The seed for the random number generator.
+///
+/// #### Returns:
+/// - Tuple of the generated dataset and the true coefficients.
+///
+pub fn make_regression(
+    n_samples: usize,
+    n_features: usize,
+    noise: f64,
+    seed: u64,
+) -> RegressionDataset {
+    let mut rng = seeded_rng(seed);
+    let coefficients: Vec<f64> = (0..n_features)
+        .map(|_| rng.gen_range(-10.0..=10.0))
+        .collect();
+
+    let mut data = Vec::with_capacity(n_samples * n_features);
+    let mut target = Vec::with_capacity(n_samples);
+    for _ in 0..n_samples {
+        let mut value = 0.0;
+        for coefficient in &coefficients {
+            let feature = standard_normal(&mut rng);
+            value += coefficient * feature;
+            data.push(feature);
+        }
+        target.push(value + noise * standard_normal(&mut rng));
+    }
+
+    (
+        Dataset::new(
+            Matrix::new(n_samples, n_features, data),
+            Vector::new(target),
+            feature_names(n_features),
+            "target".to_string(),
+        ),
+        coefficients,
+    )
+}
+
+/// Generates an evenly spaced grid of 2D points covering the given ranges,
+/// both ends included. The x coordinate varies fastest, so the first `steps`
+/// rows share the lowest y value.
+///
+/// #### Parameters:
+/// - x_range: The lowest and highest x value.
+/// - y_range: The lowest and highest y value.
+/// - steps: The number of grid values along each axis.
+///
+/// #### Returns:
+/// - A `steps² x 2` matrix with one grid point per row.
+///
+pub fn meshgrid(x_range: (f64, f64), y_range: (f64, f64), steps: usize) -> Matrix<f64> {
+    let x_values = linspace(x_range, steps);
+    let y_values = linspace(y_range, steps);
+
+    let mut data = Vec::with_capacity(steps * steps * 2);
+    for &y in &y_values {
+        for &x in &x_values {
+            data.push(x);
+            data.push(y);
+        }
+    }
+    Matrix::new(steps * steps, 2, data)
+}
+
+/// Helper function that returns `steps` evenly spaced values from the start
+/// to the end of the range, a single step gives just the start.
+fn linspace(range: (f64, f64), steps: usize) -> Vec<f64> {
+    let step = if steps > 1 {
+        (range.1 - range.0) / (steps - 1) as f64
+    } else {
+        0.0
+    };
+    // The last value is pinned to the end so rounding can't leave it short.
+    (0..steps)
+        .map(|i| {
+            if i + 1 == steps && steps > 1 {
+                range.1
+            } else {
+                range.0 + step * i as f64
+            }
+        })
+        .collect()
+}
+
+/// Helper function that names the generated features `feature_1`,
+/// `feature_2`, ...
+fn feature_names(n_features: usize) -> Vector<String> {
+    Vector::new(
+        (1..=n_features)
+            .map(|i| format!("feature_{}", i))
+            .collect::<Vec<String>>(),
+    )
+}
@@ -7,14 +7,48 @@
 //! Datasets:
 //! - Iris dataset.
 //! - Pokemon dataset.
+//! - Synthetic blobs and regression generators.
 //!
 //! Encoders:
 //! - Label encoder.
 //! - One hot encoder.
+//! - Cyclical encoder.
+//! - Feature hasher.
 //!
 //! Scalers:
 //! - MinMax scaler.
 //!
+//! Outliers:
+//! - Winsorizer.
+//!
+//! Discretizers:
+//! - KBins discretizer.
+//!
+//! Decomposition:
+//! - Linear discriminant analysis.
+//!
+//! Feature selection:
+//! - Select k best.
+//!
+//! Metrics:
+//! - ROC curve.
+//! - ROC AUC score.
+//! - Log loss.
+//! - Mutual information.
+//! - Information gain.
+//! - Gini impurity and entropy.
+//! - Silhouette score.
+//! - Classification report.
+//! - Residuals.
+//! - Mean and median absolute error.
+//! - R² and adjusted R².
+//! - Cosine similarity.
+//! - Prediction export to CSV.
+//!
+//! Model selection:
+//! - Group k-fold indices.
+//! - Stratified k-fold indices.
+//!
 
 /// Re-exports of commonnly used [rulinalg](https://github.com/AtheMathmo/rulinalg) linear
 /// algebra tools and data types.
@@ -40,6 +74,15 @@ pub mod base {
     /// Module to define errors used in this crate.
     pub mod error;
 
+    /// Module for the seeded random number generators.
+    pub mod rng;
+
+    /// Module for statistical helpers used within the crate.
+    pub(crate) mod stats;
+
+    /// Module for the symmetric eigendecomposition used within the crate.
+    pub(crate) mod eigen;
+
     /// Type alias for the use of the Result type in this crate.
     pub type MLResult<T> = Result<T, error::Error>;
 }
@@ -49,3 +92,9 @@ pub mod dataset;
 
 /// Module for some data preprocessing functionality.
 pub mod preprocessing; 
+
+/// Module for model evaluation metrics.
+pub mod metrics;
+
+/// Module for model selection and dataset splitting.
+pub mod model_selection;
@@ -0,0 +1,77 @@
+//! # Distance Module
+//!
+//! Similarity and distance measures between feature vectors.
+//!
+//! ## Examples
+//! ```
+//! use rust_ml::linalg::Vector;
+//! use rust_ml::metrics::distance::cosine_similarity;
+//!
+//! let a = Vector::new(vec![1.0, 0.0]);
+//! let b = Vector::new(vec![1.0, 1.0]);
+//!
+//! let similarity = cosine_similarity(&a, &b).unwrap();
+//! assert!((similarity - 0.5_f64.sqrt()).abs() < 1e-12);
+//! ```
+
+use crate::base::error::{Error, ErrorKind};
+use crate::base::MLResult;
+use crate::linalg::{BaseMatrix, Matrix, Vector};
+
+/// Computes the cosine of the angle between two vectors.
+///
+/// #### Parameters:
+/// - a: The first vector.
+/// - b: The second vector.
+///
+/// #### Returns:
+/// - MLResult wrapped similarity in the range [-1, 1]. A zero vector has a
+///   similarity of 0 with every vector.
+///
+pub fn cosine_similarity(a: &Vector<f64>, b: &Vector<f64>) -> MLResult<f64> {
+    if a.size() != b.size() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Length of the first vector ({}) does not match length of the second vector ({})",
+                a.size(),
+                b.size()
+            ),
+        ));
+    }
+    Ok(cosine(a.data(), b.data()))
+}
+
+/// Computes the cosine similarity between every pair of rows.
+///
+/// #### Parameters:
+/// - data: The matrix with one sample per row.
+///
+/// #### Returns:
+/// - Symmetric matrix where row `i` column `j` holds the similarity of rows
+///   `i` and `j`.
+///
+pub fn pairwise_cosine(data: &Matrix<f64>) -> Matrix<f64> {
+    let num_rows = data.rows();
+    let mut similarities = Matrix::zeros(num_rows, num_rows);
+    for i in 0..num_rows {
+        for j in i..num_rows {
+            let similarity = cosine(data.row(i).raw_slice(), data.row(j).raw_slice());
+            similarities[[i, j]] = similarity;
+            similarities[[j, i]] = similarity;
+        }
+    }
+    similarities
+}
+
+/// Helper function that computes the cosine similarity of two equally long
+/// slices, treating a zero norm as a similarity of 0.
+fn cosine(a: &[f64], b: &[f64]) -> f64 {
+    let dot: f64 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    let norms =
+        a.iter().map(|x| x * x).sum::<f64>().sqrt() * b.iter().map(|y| y * y).sum::<f64>().sqrt();
+    if norms == 0.0 {
+        return 0.0;
+    }
+    dot / norms
+}
@@ -0,0 +1,812 @@
+//! # Metrics Module
+//!
+//! Functions for evaluating model predictions.
+//!
+//! ## Features
+//! - ROC curve
+//! - ROC AUC score
+//! - Log loss
+//! - Mutual information
+//! - Information gain
+//! - Gini impurity and entropy
+//! - Silhouette score
+//! - Classification report
+//! - Residuals
+//! - Mean and median absolute error
+//! - R² and adjusted R²
+//! - Cosine similarity
+//! - Prediction export to CSV
+//!
+//! ## Examples
+//! ```
+//! use rust_ml::linalg::Vector;
+//! use rust_ml::metrics::{roc_auc_score, roc_curve};
+//!
+//! let y_true = Vector::new(vec![0.0, 0.0, 1.0, 1.0]);
+//! let y_score = Vector::new(vec![0.1, 0.4, 0.35, 0.8]);
+//! let (fpr, tpr, thresholds) = roc_curve(&y_true, &y_score).unwrap();
+//!
+//! assert_eq!(fpr, vec![0.0, 0.0, 0.5, 0.5, 1.0]);
+//! assert_eq!(tpr, vec![0.0, 0.5, 0.5, 1.0, 1.0]);
+//! assert_eq!(thresholds[1..], [0.8, 0.4, 0.35, 0.1]);
+//! assert_eq!(roc_auc_score(&y_true, &y_score).unwrap(), 0.75);
+//! ```
+
+use crate::base::error::{Error, ErrorKind};
+use crate::base::stats::{sorted_quantile, sorted_values};
+use crate::base::MLResult;
+use crate::dataset::Dataset;
+use crate::linalg::{BaseMatrix, Matrix, Vector};
+use crate::preprocessing::discretization::{BinStrategy, KBinsDiscretizerFitter};
+use crate::preprocessing::{Preprocessor, PreprocessorFitter};
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::path::Path;
+
+/// The smallest probability used by `log_loss`, predictions are clamped to
+/// `[LOG_LOSS_EPS, 1 - LOG_LOSS_EPS]` so a confident mistake has a finite loss.
+const LOG_LOSS_EPS: f64 = 1e-15;
+
+pub mod distance;
+
+/// Computes the receiver operating characteristic curve for a binary
+/// classifier. Each distinct score is used as a decision threshold, where
+/// scores greater than or equal to the threshold are predicted positive.
+///
+/// #### Parameters:
+/// - y_true: The true binary labels (0.0 or 1.0).
+/// - y_score: The predicted scores or probabilities for the positive class.
+///
+/// #### Returns:
+/// - MLResult wrapped tuple of the false positive rates, true positive rates
+///   and the decreasing thresholds used to compute them. The first point is
+///   always (0, 0) with an infinite threshold.
+///
+pub fn roc_curve(
+    y_true: &Vector<f64>,
+    y_score: &Vector<f64>,
+) -> MLResult<(Vec<f64>, Vec<f64>, Vec<f64>)> {
+    check_lengths(y_true, y_score)?;
+    let (num_positive, num_negative) = count_binary_classes(y_true)?;
+
+    // Sort the samples by descending score.
+    let mut order: Vec<usize> = (0..y_score.size()).collect();
+    order.sort_by(|&a, &b| y_score[b].total_cmp(&y_score[a]));
+
+    let mut fpr = vec![0.0];
+    let mut tpr = vec![0.0];
+    let mut thresholds = vec![f64::INFINITY];
+    let mut true_positives = 0.0;
+    let mut false_positives = 0.0;
+
+    for (position, &idx) in order.iter().enumerate() {
+        if y_true[idx] == 1.0 {
+            true_positives += 1.0;
+        } else {
+            false_positives += 1.0;
+        }
+        // Only emit a point once all samples sharing this score are counted.
+        let is_last_of_score = !matches!(
+            order.get(position + 1),
+            Some(&next) if y_score[next] == y_score[idx]
+        );
+        if is_last_of_score {
+            fpr.push(false_positives / num_negative);
+            tpr.push(true_positives / num_positive);
+            thresholds.push(y_score[idx]);
+        }
+    }
+
+    Ok((fpr, tpr, thresholds))
+}
+
+/// Computes the area under the ROC curve using the rank based Mann-Whitney U
+/// statistic. Tied scores are given their average rank, so ties count as half
+/// a correctly ordered pair.
+///
+/// #### Parameters:
+/// - y_true: The true binary labels (0.0 or 1.0).
+/// - y_score: The predicted scores or probabilities for the positive class.
+///
+/// #### Returns:
+/// - MLResult wrapped area under the curve.
+///
+pub fn roc_auc_score(y_true: &Vector<f64>, y_score: &Vector<f64>) -> MLResult<f64> {
+    check_lengths(y_true, y_score)?;
+    let (num_positive, num_negative) = count_binary_classes(y_true)?;
+
+    let mut order: Vec<usize> = (0..y_score.size()).collect();
+    order.sort_by(|&a, &b| y_score[a].total_cmp(&y_score[b]));
+
+    // Sum the (1-based, tie averaged) ranks of the positive samples.
+    let mut positive_rank_sum = 0.0;
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && y_score[order[end + 1]] == y_score[order[start]] {
+            end += 1;
+        }
+        let average_rank = (start + end) as f64 / 2.0 + 1.0;
+        for &idx in &order[start..=end] {
+            if y_true[idx] == 1.0 {
+                positive_rank_sum += average_rank;
+            }
+        }
+        start = end + 1;
+    }
+
+    let u_statistic = positive_rank_sum - num_positive * (num_positive + 1.0) / 2.0;
+    Ok(u_statistic / (num_positive * num_negative))
+}
+
+/// Computes the binary log loss (cross-entropy) of predicted probabilities.
+/// The probabilities are clamped away from 0 and 1 so a confidently wrong
+/// prediction gives a large but finite loss.
+///
+/// #### Parameters:
+/// - y_true: The true binary labels (0.0 or 1.0).
+/// - y_prob: The predicted probabilities of the positive class.
+///
+/// #### Returns:
+/// - MLResult wrapped mean negative log-likelihood, or an InvalidData error
+///   if the lengths differ or a label is not 0 or 1.
+///
+pub fn log_loss(y_true: &Vector<f64>, y_prob: &Vector<f64>) -> MLResult<f64> {
+    check_lengths(y_true, y_prob)?;
+
+    let mut total = 0.0;
+    for (&truth, &prob) in y_true.iter().zip(y_prob.iter()) {
+        let prob = prob.clamp(LOG_LOSS_EPS, 1.0 - LOG_LOSS_EPS);
+        total -= if truth == 1.0 {
+            prob.ln()
+        } else if truth == 0.0 {
+            (1.0 - prob).ln()
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Expected binary labels of 0 or 1, found {}", truth),
+            ));
+        };
+    }
+    Ok(total / y_true.size() as f64)
+}
+
+/// Estimates the mutual information between each feature and a categorical
+/// target. Each feature is discretized into `n_bins` uniform bins before the
+/// mutual information (in nats) is computed from the joint frequencies.
+///
+/// #### Parameters:
+/// - dataset: The dataset to score the features of.
+/// - n_bins: The number of bins to discretize each feature into.
+///
+/// #### Returns:
+/// - MLResult wrapped vector of (column name, mutual information) tuples
+///   sorted from most to least informative.
+///
+pub fn mutual_info_classif<Y>(
+    dataset: &Dataset<Matrix<f64>, Vector<Y>>,
+    n_bins: usize,
+) -> MLResult<Vec<(String, f64)>>
+where
+    Y: Eq + Hash + Clone + Debug,
+{
+    let binned = KBinsDiscretizerFitter::new(n_bins, BinStrategy::Uniform)
+        .fit(dataset)?
+        .transform(dataset)?;
+    let class_codes = encode_classes(dataset.target());
+    let num_samples = class_codes.len() as f64;
+
+    let mut scores = Vec::with_capacity(binned.data().cols());
+    for (idx, name) in dataset.data_columns().iter().enumerate() {
+        let bins: Vec<usize> = binned.data().col(idx).iter().map(|&b| b as usize).collect();
+        let mut joint: HashMap<(usize, usize), f64> = HashMap::new();
+        let mut bin_counts: HashMap<usize, f64> = HashMap::new();
+        let mut class_counts: HashMap<usize, f64> = HashMap::new();
+        for (&bin, &class) in bins.iter().zip(class_codes.iter()) {
+            *joint.entry((bin, class)).or_insert(0.0) += 1.0;
+            *bin_counts.entry(bin).or_insert(0.0) += 1.0;
+            *class_counts.entry(class).or_insert(0.0) += 1.0;
+        }
+
+        let mutual_info: f64 = joint
+            .iter()
+            .map(|(&(bin, class), &count)| {
+                let p_joint = count / num_samples;
+                let p_bin = bin_counts[&bin] / num_samples;
+                let p_class = class_counts[&class] / num_samples;
+                p_joint * (p_joint / (p_bin * p_class)).ln()
+            })
+            .sum();
+        // Clamp tiny negative values caused by floating point error.
+        scores.push((name.clone(), mutual_info.max(0.0)));
+    }
+
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    Ok(scores)
+}
+
+/// Computes the information gain of each feature with respect to a categorical
+/// target, the drop in target entropy (in bits) from splitting the samples by
+/// the feature's bin. Each feature is discretized into `n_bins` uniform bins.
+///
+/// #### Parameters:
+/// - dataset: The dataset to score the features of.
+/// - n_bins: The number of bins to discretize each feature into.
+///
+/// #### Returns:
+/// - MLResult wrapped vector of (column name, information gain) tuples sorted
+///   from most to least informative.
+///
+pub fn information_gain<Y>(
+    dataset: &Dataset<Matrix<f64>, Vector<Y>>,
+    n_bins: usize,
+) -> MLResult<Vec<(String, f64)>>
+where
+    Y: Eq + Hash + Clone + Debug,
+{
+    let binned = KBinsDiscretizerFitter::new(n_bins, BinStrategy::Uniform)
+        .fit(dataset)?
+        .transform(dataset)?;
+    let class_codes = encode_classes(dataset.target());
+    let num_samples = class_codes.len() as f64;
+
+    let mut class_counts: HashMap<usize, f64> = HashMap::new();
+    for &class in &class_codes {
+        *class_counts.entry(class).or_insert(0.0) += 1.0;
+    }
+    let target_entropy = count_entropy(class_counts.values());
+
+    let mut scores = Vec::with_capacity(binned.data().cols());
+    for (idx, name) in dataset.data_columns().iter().enumerate() {
+        let mut bin_class_counts: HashMap<usize, HashMap<usize, f64>> = HashMap::new();
+        for (&bin, &class) in binned.data().col(idx).iter().zip(class_codes.iter()) {
+            *bin_class_counts
+                .entry(bin as usize)
+                .or_default()
+                .entry(class)
+                .or_insert(0.0) += 1.0;
+        }
+
+        let conditional_entropy: f64 = bin_class_counts
+            .values()
+            .map(|counts| {
+                counts.values().sum::<f64>() / num_samples * count_entropy(counts.values())
+            })
+            .sum();
+        // Clamp tiny negative values caused by floating point error.
+        scores.push((
+            name.clone(),
+            (target_entropy - conditional_entropy).max(0.0),
+        ));
+    }
+
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+    Ok(scores)
+}
+
+/// Computes the mean silhouette coefficient of a clustering. For each sample
+/// the coefficient is `(b - a) / max(a, b)`, where `a` is the mean distance to
+/// the other members of its cluster and `b` is the mean distance to the
+/// members of the nearest other cluster. Samples in singleton clusters, and
+/// all samples when there is only one cluster, contribute 0.
+///
+/// #### Parameters:
+/// - data: The clustered samples, one per row.
+/// - labels: The cluster assignment of each sample.
+///
+/// #### Returns:
+/// - MLResult wrapped mean silhouette coefficient in the range [-1, 1].
+///
+pub fn silhouette_score(data: &Matrix<f64>, labels: &Vector<usize>) -> MLResult<f64> {
+    if data.rows() != labels.size() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Number of samples ({}) does not match number of labels ({})",
+                data.rows(),
+                labels.size()
+            ),
+        ));
+    }
+    if data.rows() == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Cannot compute a metric on empty input",
+        ));
+    }
+
+    let mut cluster_sizes: HashMap<usize, f64> = HashMap::new();
+    for &label in labels.iter() {
+        *cluster_sizes.entry(label).or_insert(0.0) += 1.0;
+    }
+    if cluster_sizes.len() < 2 {
+        return Ok(0.0);
+    }
+
+    let mut total = 0.0;
+    for i in 0..data.rows() {
+        let own_cluster = labels[i];
+        if cluster_sizes[&own_cluster] == 1.0 {
+            continue;
+        }
+        // Sum the distances from sample i to every cluster.
+        let mut distance_sums: HashMap<usize, f64> = HashMap::new();
+        for j in 0..data.rows() {
+            if i != j {
+                let distance = euclidean(data.row(i).raw_slice(), data.row(j).raw_slice());
+                *distance_sums.entry(labels[j]).or_insert(0.0) += distance;
+            }
+        }
+        let intra = distance_sums[&own_cluster] / (cluster_sizes[&own_cluster] - 1.0);
+        let nearest = distance_sums
+            .iter()
+            .filter(|(&cluster, _)| cluster != own_cluster)
+            .map(|(cluster, sum)| sum / cluster_sizes[cluster])
+            .fold(f64::INFINITY, f64::min);
+        let scale = intra.max(nearest);
+        if scale > 0.0 {
+            total += (nearest - intra) / scale;
+        }
+    }
+
+    Ok(total / data.rows() as f64)
+}
+
+/// Struct for the classification metrics of a single class.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassReport<Y> {
+    /// The class label.
+    label: Y,
+    /// The fraction of the predictions of this class that were correct.
+    precision: f64,
+    /// The fraction of the samples of this class that were predicted correctly.
+    recall: f64,
+    /// The harmonic mean of the precision and recall.
+    f1: f64,
+    /// The number of samples of this class.
+    support: usize,
+}
+
+impl<Y> ClassReport<Y> {
+    /// Returns a reference to the class label.
+    pub fn label(&self) -> &Y {
+        &self.label
+    }
+
+    /// Returns the precision.
+    pub fn precision(&self) -> f64 {
+        self.precision
+    }
+
+    /// Returns the recall.
+    pub fn recall(&self) -> f64 {
+        self.recall
+    }
+
+    /// Returns the F1 score.
+    pub fn f1(&self) -> f64 {
+        self.f1
+    }
+
+    /// Returns the number of samples of this class.
+    pub fn support(&self) -> usize {
+        self.support
+    }
+}
+
+/// Computes the precision, recall, F1 score and support of every class from
+/// the confusion matrix. Metrics with a zero denominator are reported as 0.
+///
+/// #### Parameters:
+/// - y_true: The true class labels.
+/// - y_pred: The predicted class labels.
+///
+/// #### Returns:
+/// - MLResult wrapped vector with a ClassReport for every class seen in either
+///   vector, sorted by label.
+///
+pub fn classification_report<Y>(
+    y_true: &Vector<Y>,
+    y_pred: &Vector<Y>,
+) -> MLResult<Vec<ClassReport<Y>>>
+where
+    Y: Eq + Hash + Clone + Ord,
+{
+    check_lengths(y_true, y_pred)?;
+    let (labels, matrix) = confusion_matrix(y_true, y_pred);
+
+    Ok(labels
+        .into_iter()
+        .enumerate()
+        .map(|(idx, label)| {
+            let true_positives = matrix[idx][idx] as f64;
+            let support: usize = matrix[idx].iter().sum();
+            let predicted: usize = matrix.iter().map(|row| row[idx]).sum();
+            let ratio = |count: usize| {
+                if count == 0 {
+                    0.0
+                } else {
+                    true_positives / count as f64
+                }
+            };
+            let precision = ratio(predicted);
+            let recall = ratio(support);
+            let f1 = if precision + recall == 0.0 {
+                0.0
+            } else {
+                2.0 * precision * recall / (precision + recall)
+            };
+            ClassReport {
+                label,
+                precision,
+                recall,
+                f1,
+                support,
+            }
+        })
+        .collect())
+}
+
+/// Computes the Gini impurity of a label vector, the probability that two
+/// labels drawn at random (with replacement) differ.
+///
+/// #### Parameters:
+/// - labels: The class labels.
+///
+/// #### Returns:
+/// - The Gini impurity, 0.0 for a pure or empty vector.
+///
+pub fn gini_impurity<Y: Eq + Hash>(labels: &Vector<Y>) -> f64 {
+    if labels.size() == 0 {
+        return 0.0;
+    }
+    let total = labels.size() as f64;
+    1.0 - label_counts(labels)
+        .values()
+        .map(|&c| (c / total).powi(2))
+        .sum::<f64>()
+}
+
+/// Computes the entropy, in bits, of a label vector.
+///
+/// #### Parameters:
+/// - labels: The class labels.
+///
+/// #### Returns:
+/// - The entropy, 0.0 for a pure or empty vector.
+///
+pub fn entropy<Y: Eq + Hash>(labels: &Vector<Y>) -> f64 {
+    count_entropy(label_counts(labels).values())
+}
+
+/// Computes the regression residuals `y_true - y_pred`.
+///
+/// #### Parameters:
+/// - y_true: The true target values.
+/// - y_pred: The predicted target values.
+///
+/// #### Returns:
+/// - MLResult wrapped vector of residuals.
+///
+pub fn residuals(y_true: &Vector<f64>, y_pred: &Vector<f64>) -> MLResult<Vector<f64>> {
+    check_lengths(y_true, y_pred)?;
+    Ok(y_true - y_pred)
+}
+
+/// Computes the residuals divided by their (population) standard deviation,
+/// which makes unusually large residuals easy to spot.
+///
+/// #### Parameters:
+/// - y_true: The true target values.
+/// - y_pred: The predicted target values.
+///
+/// #### Returns:
+/// - MLResult wrapped vector of standardized residuals, or an InvalidData error
+///   if all residuals are equal.
+///
+pub fn standardized_residuals(y_true: &Vector<f64>, y_pred: &Vector<f64>) -> MLResult<Vector<f64>> {
+    let residuals = residuals(y_true, y_pred)?;
+    let n = residuals.size() as f64;
+    let mean = residuals.sum() / n;
+    let std = (residuals.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / n).sqrt();
+    if std == 0.0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Cannot standardize residuals with zero variance",
+        ));
+    }
+    Ok(residuals / std)
+}
+
+/// Computes the coefficient of determination (R²) of the predictions.
+///
+/// #### Parameters:
+/// - y_true: The true target values.
+/// - y_pred: The predicted target values.
+///
+/// #### Returns:
+/// - MLResult wrapped R² score, or an InvalidData error if the true values
+///   are constant.
+///
+pub fn r2_score(y_true: &Vector<f64>, y_pred: &Vector<f64>) -> MLResult<f64> {
+    let residuals = residuals(y_true, y_pred)?;
+    let mean = y_true.sum() / y_true.size() as f64;
+    let total_sum_squares: f64 = y_true.iter().map(|y| (y - mean).powi(2)).sum();
+    if total_sum_squares == 0.0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Cannot compute R² for a constant target",
+        ));
+    }
+    let residual_sum_squares: f64 = residuals.iter().map(|r| r * r).sum();
+    Ok(1.0 - residual_sum_squares / total_sum_squares)
+}
+
+/// Computes the R² adjusted for the number of features used by the model,
+/// `1 - (1 - R²)(n - 1) / (n - p - 1)`, so models with different feature
+/// counts can be compared.
+///
+/// #### Parameters:
+/// - y_true: The true target values.
+/// - y_pred: The predicted target values.
+/// - n_features: The number of features the model used.
+///
+/// #### Returns:
+/// - MLResult wrapped adjusted R² score, or an InvalidParameters error if
+///   there are not more samples than features plus one.
+///
+pub fn adjusted_r2_score(
+    y_true: &Vector<f64>,
+    y_pred: &Vector<f64>,
+    n_features: usize,
+) -> MLResult<f64> {
+    let r2 = r2_score(y_true, y_pred)?;
+    let n = y_true.size();
+    if n <= n_features + 1 {
+        return Err(Error::new(
+            ErrorKind::InvalidParameters,
+            format!(
+                "Number of samples ({}) must exceed the number of features plus one ({})",
+                n,
+                n_features + 1
+            ),
+        ));
+    }
+    Ok(1.0 - (1.0 - r2) * (n - 1) as f64 / (n - n_features - 1) as f64)
+}
+
+/// Computes the mean absolute error between the true and predicted values.
+///
+/// #### Parameters:
+/// - y_true: The true target values.
+/// - y_pred: The predicted target values.
+///
+/// #### Returns:
+/// - MLResult wrapped mean of the absolute residuals.
+///
+pub fn mean_absolute_error(y_true: &Vector<f64>, y_pred: &Vector<f64>) -> MLResult<f64> {
+    let residuals = residuals(y_true, y_pred)?;
+    Ok(residuals.iter().map(|r| r.abs()).sum::<f64>() / residuals.size() as f64)
+}
+
+/// Computes the median absolute error between the true and predicted values,
+/// which unlike the mean absolute error is robust to a few large outliers.
+///
+/// #### Parameters:
+/// - y_true: The true target values.
+/// - y_pred: The predicted target values.
+///
+/// #### Returns:
+/// - MLResult wrapped median of the absolute residuals.
+///
+pub fn median_absolute_error(y_true: &Vector<f64>, y_pred: &Vector<f64>) -> MLResult<f64> {
+    let residuals = residuals(y_true, y_pred)?;
+    let absolute: Vec<f64> = residuals.iter().map(|r| r.abs()).collect();
+    Ok(sorted_quantile(&sorted_values(absolute.iter()), 0.5))
+}
+
+/// Writes each sample's features, true target and prediction to a CSV file
+/// for reporting. The header is the feature columns, the target column and a
+/// final `prediction` column.
+///
+/// #### Parameters:
+/// - dataset: The dataset the predictions were made on.
+/// - predictions: The prediction for each row of the dataset.
+/// - path: The path of the CSV file to write, replaced if it exists.
+///
+/// #### Returns:
+/// - Empty MLResult, or an InvalidData error if the number of predictions
+///   does not match the number of rows or the file cannot be written.
+///
+pub fn predictions_to_csv<Y, P>(
+    dataset: &Dataset<Matrix<f64>, Vector<Y>>,
+    predictions: &Vector<Y>,
+    path: P,
+) -> MLResult<()>
+where
+    Y: Clone + Debug + Display,
+    P: AsRef<Path>,
+{
+    if predictions.size() != dataset.data().rows() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Number of predictions ({}) does not match number of rows ({})",
+                predictions.size(),
+                dataset.data().rows()
+            ),
+        ));
+    }
+
+    let mut writer =
+        csv::Writer::from_path(path).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+    let mut header: Vec<&str> = dataset.data_columns().iter().map(|c| c.as_str()).collect();
+    header.push(dataset.target_column());
+    header.push("prediction");
+    writer
+        .write_record(&header)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+    for ((row, target), prediction) in dataset
+        .data()
+        .row_iter()
+        .zip(dataset.target().iter())
+        .zip(predictions.iter())
+    {
+        let mut record: Vec<String> = row.raw_slice().iter().map(|v| v.to_string()).collect();
+        record.push(target.to_string());
+        record.push(prediction.to_string());
+        writer
+            .write_record(&record)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+    }
+    writer
+        .flush()
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+}
+
+/// Helper function that computes the euclidean distance between two points.
+fn euclidean(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Helper function that counts how often each true class was predicted as
+/// each class.
+///
+/// #### Parameters:
+/// - y_true: The true class labels.
+/// - y_pred: The predicted class labels.
+///
+/// #### Returns:
+/// - Tuple of the sorted labels seen in either vector and the confusion
+///   matrix, where row `i` column `j` counts samples of class `i` predicted as
+///   class `j`.
+///
+fn confusion_matrix<Y: Eq + Hash + Clone + Ord>(
+    y_true: &Vector<Y>,
+    y_pred: &Vector<Y>,
+) -> (Vec<Y>, Vec<Vec<usize>>) {
+    let mut labels: Vec<Y> = y_true.iter().chain(y_pred.iter()).cloned().collect();
+    labels.sort();
+    labels.dedup();
+    let positions: HashMap<&Y, usize> = labels.iter().enumerate().map(|(i, l)| (l, i)).collect();
+
+    let mut matrix = vec![vec![0; labels.len()]; labels.len()];
+    for (truth, prediction) in y_true.iter().zip(y_pred.iter()) {
+        matrix[positions[truth]][positions[prediction]] += 1;
+    }
+    (labels, matrix)
+}
+
+/// Helper function that computes the entropy, in bits, of a distribution
+/// given by its counts.
+fn count_entropy<'a, I: Iterator<Item = &'a f64>>(counts: I) -> f64 {
+    let counts: Vec<f64> = counts.copied().collect();
+    let total: f64 = counts.iter().sum();
+    counts
+        .iter()
+        .filter(|&&c| c > 0.0)
+        .map(|&c| {
+            let p = c / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Helper function that counts how often each label occurs.
+fn label_counts<Y: Eq + Hash>(labels: &Vector<Y>) -> HashMap<&Y, f64> {
+    let mut counts = HashMap::new();
+    for label in labels.iter() {
+        *counts.entry(label).or_insert(0.0) += 1.0;
+    }
+    counts
+}
+
+/// Helper function that maps each class label to an integer code in the order
+/// the classes first appear.
+///
+/// #### Parameters:
+/// - labels: The class labels.
+///
+/// #### Returns:
+/// - The integer code of each label.
+///
+fn encode_classes<Y: Eq + Hash>(labels: &Vector<Y>) -> Vec<usize> {
+    let mut codes: HashMap<&Y, usize> = HashMap::new();
+    labels
+        .iter()
+        .map(|label| {
+            let next_code = codes.len();
+            *codes.entry(label).or_insert(next_code)
+        })
+        .collect()
+}
+
+/// Helper function that makes sure the true and predicted vectors are the
+/// same, non-zero length.
+///
+/// #### Parameters:
+/// - y_true: The true values.
+/// - y_pred: The predicted values.
+///
+/// #### Returns:
+/// - Empty MLResult or an InvalidData error.
+///
+fn check_lengths<T, U>(y_true: &Vector<T>, y_pred: &Vector<U>) -> MLResult<()> {
+    if y_true.size() != y_pred.size() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "Length of y_true ({}) does not match length of predictions ({})",
+                y_true.size(),
+                y_pred.size()
+            ),
+        ));
+    }
+    if y_true.size() == 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Cannot compute a metric on empty input",
+        ));
+    }
+    Ok(())
+}
+
+/// Helper function that makes sure a label vector only contains 0.0 and 1.0
+/// and that both classes are present.
+///
+/// #### Parameters:
+/// - y_true: The true binary labels.
+///
+/// #### Returns:
+/// - MLResult wrapped tuple of the positive and negative counts.
+///
+fn count_binary_classes(y_true: &Vector<f64>) -> MLResult<(f64, f64)> {
+    let mut num_positive = 0.0;
+    let mut num_negative = 0.0;
+    for &value in y_true.iter() {
+        if value == 1.0 {
+            num_positive += 1.0;
+        } else if value == 0.0 {
+            num_negative += 1.0;
+        } else {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("Expected binary labels of 0 or 1, found {}", value),
+            ));
+        }
+    }
+    if num_positive == 0.0 || num_negative == 0.0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "Both the positive and negative class must be present in y_true",
+        ));
+    }
+    Ok((num_positive, num_negative))
+}
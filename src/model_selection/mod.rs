@@ -0,0 +1,172 @@
+//! # Model Selection Module
+//!
+//! Tools for splitting datasets into training and validation sets.
+//!
+//! ## Features
+//! - Group k-fold indices
+//! - Stratified k-fold indices
+//!
+//! ## Examples
+//! ```
+//! use rust_ml::linalg::Vector;
+//! use rust_ml::model_selection::group_kfold_indices;
+//!
+//! let groups = Vector::new(vec!["a", "a", "b", "c", "c", "c"]);
+//! let folds = group_kfold_indices(&groups, 2).unwrap();
+//!
+//! assert_eq!(folds.len(), 2);
+//! assert_eq!(folds[0].1, vec![3, 4, 5]);
+//! assert_eq!(folds[1].1, vec![0, 1, 2]);
+//! ```
+
+use crate::base::error::{Error, ErrorKind};
+use crate::base::rng::seeded_rng;
+use crate::base::MLResult;
+use crate::linalg::Vector;
+
+use rand::seq::SliceRandom;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Splits sample indices into `k` folds such that all samples sharing a group
+/// value land in the same fold. Groups are assigned largest first to the fold
+/// with the fewest samples so the folds are as balanced as possible.
+///
+/// #### Parameters:
+/// - groups: The group value of each sample.
+/// - k: The number of folds.
+///
+/// #### Returns:
+/// - MLResult wrapped vector of (train indices, test indices) tuples, one for
+///   each fold.
+///
+pub fn group_kfold_indices<Y>(
+    groups: &Vector<Y>,
+    k: usize,
+) -> MLResult<Vec<(Vec<usize>, Vec<usize>)>>
+where
+    Y: Eq + Hash + Clone,
+{
+    if k < 2 {
+        return Err(Error::new(
+            ErrorKind::InvalidParameters,
+            format!("Number of folds must be at least 2, got {}", k),
+        ));
+    }
+
+    let mut members = group_members(groups);
+    if k > members.len() {
+        return Err(Error::new(
+            ErrorKind::InvalidParameters,
+            format!(
+                "Number of folds ({}) cannot exceed the number of distinct groups ({})",
+                k,
+                members.len()
+            ),
+        ));
+    }
+
+    // Stable sort keeps the first-seen order between equally sized groups.
+    members.sort_by_key(|group| std::cmp::Reverse(group.len()));
+    let mut fold_of_sample = vec![0; groups.size()];
+    let mut fold_sizes = vec![0; k];
+    for group in &members {
+        let fold = (0..k).min_by_key(|&f| fold_sizes[f]).unwrap();
+        fold_sizes[fold] += group.len();
+        for &idx in group {
+            fold_of_sample[idx] = fold;
+        }
+    }
+
+    Ok(split_folds(&fold_of_sample, k))
+}
+
+/// Splits sample indices into `k` folds whose test sets preserve the overall
+/// class distribution. The members of each class are dealt out to the folds
+/// in turn, continuing from the fold the previous class stopped at, so the
+/// fold sizes differ by at most one.
+///
+/// #### Parameters:
+/// - target: The class label of each sample.
+/// - k: The number of folds.
+/// - seed: Optional seed to shuffle the samples within each class before they
+///   are dealt out. Without a seed the samples keep their order.
+///
+/// #### Returns:
+/// - MLResult wrapped vector of (train indices, test indices) tuples, one for
+///   each fold.
+///
+pub fn stratified_kfold_indices<Y>(
+    target: &Vector<Y>,
+    k: usize,
+    seed: Option<u64>,
+) -> MLResult<Vec<(Vec<usize>, Vec<usize>)>>
+where
+    Y: Eq + Hash + Clone,
+{
+    if k < 2 {
+        return Err(Error::new(
+            ErrorKind::InvalidParameters,
+            format!("Number of folds must be at least 2, got {}", k),
+        ));
+    }
+
+    let mut members = group_members(target);
+    if let Some(smallest) = members.iter().map(|class| class.len()).min() {
+        if smallest < k {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!(
+                    "Number of folds ({}) cannot exceed the number of members in the smallest class ({})",
+                    k, smallest
+                ),
+            ));
+        }
+    }
+    if let Some(seed) = seed {
+        let mut rng = seeded_rng(seed);
+        for class in members.iter_mut() {
+            class.shuffle(&mut rng);
+        }
+    }
+
+    let mut fold_of_sample = vec![0; target.size()];
+    let mut next_fold = 0;
+    for class in &members {
+        for &idx in class {
+            fold_of_sample[idx] = next_fold;
+            next_fold = (next_fold + 1) % k;
+        }
+    }
+
+    Ok(split_folds(&fold_of_sample, k))
+}
+
+/// Helper function that collects the member indices of each distinct value,
+/// in the order the values first appear.
+fn group_members<Y: Eq + Hash>(values: &Vector<Y>) -> Vec<Vec<usize>> {
+    let mut positions: HashMap<&Y, usize> = HashMap::new();
+    let mut members: Vec<Vec<usize>> = Vec::new();
+    for (idx, value) in values.iter().enumerate() {
+        match positions.get(value) {
+            Some(&position) => members[position].push(idx),
+            None => {
+                positions.insert(value, members.len());
+                members.push(vec![idx]);
+            }
+        }
+    }
+    members
+}
+
+/// Helper function that turns the fold assignment of each sample into
+/// (train indices, test indices) tuples, one for each fold.
+fn split_folds(fold_of_sample: &[usize], k: usize) -> Vec<(Vec<usize>, Vec<usize>)> {
+    (0..k)
+        .map(|fold| {
+            let (test, train): (Vec<usize>, Vec<usize>) =
+                (0..fold_of_sample.len()).partition(|&idx| fold_of_sample[idx] == fold);
+            (train, test)
+        })
+        .collect()
+}
@@ -0,0 +1,290 @@
+//! # Linear Discriminant Analysis
+//!
+//! This module defines a supervised dimensionality reducer that projects the
+//! features onto the directions that best separate the target classes. The
+//! directions are found by solving the generalized eigenproblem of the
+//! between-class and within-class scatter matrices, so at most
+//! `n_classes - 1` components can be kept.
+//!
+//! ## Examples
+//! ```
+//! use rust_ml::dataset::iris;
+//! use rust_ml::linalg::BaseMatrix;
+//! use rust_ml::preprocessing::decomposition::lda::LDAFitter;
+//! use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+//!
+//! let iris_dataset = iris::load();
+//!
+//! let lda_fitter = LDAFitter::new(2);
+//! let mut lda = lda_fitter.fit(&iris_dataset).unwrap();
+//! let reduced_dataset = lda.transform(&iris_dataset).unwrap();
+//!
+//! assert_eq!(lda.fitter().fit_status(), &FitStatus::Fit);
+//! assert_eq!(reduced_dataset.data().cols(), 2);
+//! ```
+
+use crate::base::eigen::symmetric_eigendecomp;
+use crate::base::error::{Error, ErrorKind};
+use crate::base::MLResult;
+use crate::dataset::Dataset;
+use crate::linalg::{Axes, BaseMatrix, Matrix, Vector};
+use crate::preprocessing::{
+    check_fit, DatasetTransform, FitStatus, Preprocessor, PreprocessorFitter,
+};
+
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Struct for the Linear Discriminant Analysis transformer.
+#[derive(Clone, Debug)]
+pub struct LDA<Y> {
+    /// The fitter.
+    fitter: LDAFitter<Y>,
+}
+
+impl<Y> LDA<Y> {
+    /// Creates an LDA transformer that has not been fit yet from the fitter's
+    /// number of components, to be fit later through `DatasetTransform`. Any
+    /// directions the fitter was already fit with are discarded.
+    ///
+    /// #### Parameters:
+    /// - fitter: The fitter holding the number of components.
+    ///
+    pub fn unfit_from(fitter: LDAFitter<Y>) -> Self {
+        LDA {
+            fitter: LDAFitter::new(fitter.n_components),
+        }
+    }
+
+    /// Returns a reference to the fitter.
+    pub fn fitter(&self) -> &LDAFitter<Y> {
+        &self.fitter
+    }
+}
+
+impl<Y> Preprocessor<Dataset<Matrix<f64>, Vector<Y>>> for LDA<Y>
+where
+    Y: Clone + Debug + Eq + Hash,
+{
+    type O = Dataset<Matrix<f64>, Vector<Y>>;
+
+    /// Centers the features on the fitted mean and projects them onto the
+    /// discriminant directions.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to reduce.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset with one column per discriminant component.
+    ///
+    fn transform(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<Self::O> {
+        DatasetTransform::transform(self, input)
+    }
+}
+
+impl<Y> DatasetTransform<Y> for LDA<Y>
+where
+    Y: Clone + Debug + Eq + Hash,
+{
+    /// Refits the discriminant directions on a given dataset, keeping the
+    /// number of components.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - Empty MLResult.
+    ///
+    fn fit(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<()> {
+        *self = LDAFitter::new(self.fitter.n_components).fit(input)?;
+        Ok(())
+    }
+
+    /// Centers the features on the fitted mean and projects them onto the
+    /// discriminant directions.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to reduce.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset with one column per discriminant component.
+    ///
+    fn transform(
+        &self,
+        input: &Dataset<Matrix<f64>, Vector<Y>>,
+    ) -> MLResult<Dataset<Matrix<f64>, Vector<Y>>> {
+        check_fit(&self.fitter.fit)?;
+        let num_features = self.fitter.mean.size();
+        if num_features != input.data_columns().size() {
+            return Err(Error::new(
+                ErrorKind::InvalidState,
+                format!(
+                    "Fitter's number of features ({}) does not match dataset's number of features ({})",
+                    num_features,
+                    input.data_columns().size()
+                ),
+            ));
+        }
+
+        let projected = center(input.data(), &self.fitter.mean) * &self.fitter.components;
+
+        Ok(Dataset::new(
+            projected,
+            input.target().clone(),
+            Vector::new(
+                (1..=self.fitter.n_components)
+                    .map(|i| format!("LD{}", i))
+                    .collect::<Vec<String>>(),
+            ),
+            input.target_column().to_string(),
+        ))
+    }
+}
+
+/// Struct for the fitter for the Linear Discriminant Analysis transformer.
+#[derive(Clone, Debug)]
+pub struct LDAFitter<Y> {
+    /// The number of discriminant components to keep.
+    n_components: usize,
+    /// The overall mean of each feature.
+    mean: Vector<f64>,
+    /// The discriminant directions, one per column, ordered by decreasing
+    /// class separation.
+    components: Matrix<f64>,
+    /// The eigenvalue of each kept discriminant direction.
+    eigenvalues: Vec<f64>,
+    /// Indicates whether the fitter has been fit.
+    fit: FitStatus,
+    phantom: std::marker::PhantomData<Y>,
+}
+
+impl<Y> LDAFitter<Y> {
+    /// Create a new instance of the LDAFitter.
+    ///
+    /// #### Parameters
+    /// - n_components: The number of discriminant components to keep, must be
+    ///   at most the number of classes minus one.
+    ///
+    pub fn new(n_components: usize) -> Self {
+        LDAFitter {
+            n_components,
+            mean: Vector::zeros(0),
+            components: Matrix::zeros(0, 0),
+            eigenvalues: Vec::new(),
+            fit: FitStatus::NotFit,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of discriminant components to keep.
+    pub fn n_components(&self) -> &usize {
+        &self.n_components
+    }
+
+    /// Returns a reference to the overall feature means.
+    pub fn mean(&self) -> &Vector<f64> {
+        &self.mean
+    }
+
+    /// Returns a reference to the discriminant directions (one per column).
+    pub fn components(&self) -> &Matrix<f64> {
+        &self.components
+    }
+
+    /// Returns a reference to the eigenvalues of the kept directions.
+    pub fn eigenvalues(&self) -> &Vec<f64> {
+        &self.eigenvalues
+    }
+}
+
+impl<Y> PreprocessorFitter<Dataset<Matrix<f64>, Vector<Y>>, LDA<Y>> for LDAFitter<Y>
+where
+    Y: Clone + Debug + Eq + Hash,
+{
+    /// Fits the discriminant directions on a given dataset.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped LDA.
+    ///
+    fn fit(mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<LDA<Y>> {
+        let num_features = input.data().cols();
+        // Classes are visited in the order they first appear so the scatter
+        // sums are accumulated in the same order on every run.
+        let class_rows = input.class_indices();
+        let num_classes = class_rows.len();
+
+        if self.n_components == 0
+            || self.n_components >= num_classes
+            || self.n_components > num_features
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!(
+                    "Number of components ({}) must be between 1 and the number of classes minus one ({}), and at most the number of features ({})",
+                    self.n_components,
+                    num_classes.saturating_sub(1),
+                    num_features
+                ),
+            ));
+        }
+
+        let mean = input.data().mean(Axes::Row);
+        let within_scatter = input.within_class_scatter()?;
+        let mut between_scatter = Matrix::zeros(num_features, num_features);
+        for (_, indices) in class_rows.iter() {
+            let class_mean = input.data().select_rows(indices).mean(Axes::Row);
+            let mean_difference = Matrix::new(num_features, 1, (&class_mean - &mean).into_vec());
+            between_scatter +=
+                &mean_difference * mean_difference.transpose() * indices.len() as f64;
+        }
+
+        // Reduce the generalized problem Sb w = λ Sw w to a symmetric one using
+        // the Cholesky factor Sw = L Lᵀ, solve for v = Lᵀ w and map back.
+        let lower = within_scatter.cholesky().map_err(|e| {
+            Error::new(
+                ErrorKind::LinAlgError,
+                format!(
+                    "Within-class scatter matrix is not positive definite.\n{}",
+                    e
+                ),
+            )
+        })?;
+        let lower_inverse = lower
+            .inverse()
+            .map_err(|e| Error::new(ErrorKind::LinAlgError, e))?;
+        let symmetric = &lower_inverse * between_scatter * lower_inverse.transpose();
+        let (values, vectors) = symmetric_eigendecomp(&symmetric);
+
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|&a, &b| values[b].total_cmp(&values[a]));
+        order.truncate(self.n_components);
+        let components = lower_inverse.transpose() * vectors.select_cols(&order);
+
+        self.eigenvalues = order.iter().map(|&i| values[i]).collect();
+        self.mean = mean;
+        self.components = components;
+        self.fit = FitStatus::Fit;
+        Ok(LDA { fitter: self })
+    }
+
+    /// Get the fit status for the preprocessor fitter.
+    fn fit_status(&self) -> &FitStatus {
+        &self.fit
+    }
+}
+
+/// Helper function that subtracts the given mean from every row of the data.
+///
+/// #### Parameters:
+/// - data: The data matrix.
+/// - mean: The mean of each column.
+///
+/// #### Returns:
+/// - The centered data matrix.
+///
+fn center(data: &Matrix<f64>, mean: &Vector<f64>) -> Matrix<f64> {
+    data - Matrix::new(data.rows(), data.cols(), mean.data().repeat(data.rows()))
+}
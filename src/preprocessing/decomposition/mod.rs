@@ -0,0 +1,9 @@
+//! # Decomposition Module
+//!
+//! The module for the dimensionality reduction transformers.
+//!
+//! ## Features
+//! - Linear Discriminant Analysis
+
+/// Module for linear discriminant analysis.
+pub mod lda;
@@ -0,0 +1,257 @@
+//! # Discretization Module
+//!
+//! This module defines the KBins discretizer, which bins each continuous
+//! feature into `n_bins` intervals and replaces the values with their bin
+//! index. Bin edges can be spaced uniformly over the feature range or placed
+//! on the feature quantiles.
+//!
+//! ## Examples
+//! ```
+//! use rust_ml::dataset::iris;
+//! use rust_ml::linalg::BaseMatrix;
+//! use rust_ml::preprocessing::discretization::{BinStrategy, KBinsDiscretizerFitter};
+//! use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+//!
+//! let iris_dataset = iris::load();
+//!
+//! let kbins_fitter = KBinsDiscretizerFitter::new(4, BinStrategy::Uniform);
+//! let mut kbins = kbins_fitter.fit(&iris_dataset).unwrap();
+//! let binned_dataset = kbins.transform(&iris_dataset).unwrap();
+//!
+//! assert_eq!(kbins.fitter().fit_status(), &FitStatus::Fit);
+//! assert!(binned_dataset.data().iter().all(|&v| v >= 0.0 && v <= 3.0));
+//! ```
+
+use crate::base::error::{Error, ErrorKind};
+use crate::base::stats::{sorted_quantile, sorted_values};
+use crate::base::MLResult;
+use crate::dataset::Dataset;
+use crate::linalg::{BaseMatrix, Matrix, Vector};
+use crate::preprocessing::{
+    check_fit, DatasetTransform, FitStatus, Preprocessor, PreprocessorFitter,
+};
+use std::fmt::Debug;
+
+/// Enum for how the bin edges are placed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BinStrategy {
+    /// Bins have equal widths over the feature range.
+    Uniform,
+    /// Bins hold (roughly) the same number of samples.
+    Quantile,
+}
+
+/// Struct for the KBins discretizer.
+#[derive(Clone, Debug)]
+pub struct KBinsDiscretizer<Y> {
+    /// The fitter.
+    fitter: KBinsDiscretizerFitter<Y>,
+}
+
+impl<Y> KBinsDiscretizer<Y> {
+    /// Creates a discretizer that has not been fit yet from the fitter's number
+    /// of bins and strategy, to be fit later through `DatasetTransform`. Any bin
+    /// edges the fitter was already fit with are discarded.
+    ///
+    /// #### Parameters:
+    /// - fitter: The fitter holding the number of bins and the bin strategy.
+    ///
+    pub fn unfit_from(fitter: KBinsDiscretizerFitter<Y>) -> Self {
+        KBinsDiscretizer {
+            fitter: KBinsDiscretizerFitter::new(fitter.n_bins, fitter.strategy),
+        }
+    }
+
+    /// Returns a reference to the fitter.
+    pub fn fitter(&self) -> &KBinsDiscretizerFitter<Y> {
+        &self.fitter
+    }
+}
+
+impl<Y> Preprocessor<Dataset<Matrix<f64>, Vector<Y>>> for KBinsDiscretizer<Y>
+where
+    Y: Clone + Debug,
+{
+    type O = Dataset<Matrix<f64>, Vector<Y>>;
+
+    /// Replaces each feature value with the index of its bin. Values outside
+    /// the fitted range are clamped into the first or last bin.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to discretize.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped discretized Dataset.
+    ///
+    fn transform(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<Self::O> {
+        DatasetTransform::transform(self, input)
+    }
+}
+
+impl<Y> DatasetTransform<Y> for KBinsDiscretizer<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Refits the bin edges on a given dataset, keeping the bin settings.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - Empty MLResult.
+    ///
+    fn fit(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<()> {
+        let fitter = KBinsDiscretizerFitter::new(self.fitter.n_bins, self.fitter.strategy.clone());
+        *self = fitter.fit(input)?;
+        Ok(())
+    }
+
+    /// Replaces each feature value with the index of its bin. Values outside
+    /// the fitted range are clamped into the first or last bin.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to discretize.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped discretized Dataset.
+    ///
+    fn transform(
+        &self,
+        input: &Dataset<Matrix<f64>, Vector<Y>>,
+    ) -> MLResult<Dataset<Matrix<f64>, Vector<Y>>> {
+        check_fit(&self.fitter.fit)?;
+        let num_features = self.fitter.bin_edges.len();
+        if num_features != input.data_columns().size() {
+            return Err(Error::new(
+                ErrorKind::InvalidState,
+                format!(
+                    "Fitter's number of features ({}) does not match dataset's number of features ({})",
+                    num_features,
+                    input.data_columns().size()
+                ),
+            ));
+        }
+        let mut binned_data = Vec::with_capacity(input.data().data().len());
+
+        for row in input.data().row_iter() {
+            for (idx, &value) in row.iter().enumerate() {
+                // Count the interior edges at or below the value, this keeps the
+                // bin index within 0..n_bins.
+                let edges = &self.fitter.bin_edges[idx];
+                let bin = edges[1..edges.len() - 1]
+                    .iter()
+                    .filter(|&&edge| value >= edge)
+                    .count();
+                binned_data.push(bin as f64);
+            }
+        }
+
+        Ok(Dataset::new(
+            Matrix::new(input.data().rows(), num_features, binned_data),
+            input.target().clone(),
+            input.data_columns().clone(),
+            input.target_column().to_string(),
+        ))
+    }
+}
+
+/// Struct for the fitter for the KBins discretizer.
+#[derive(Clone, Debug)]
+pub struct KBinsDiscretizerFitter<Y> {
+    /// The number of bins per feature.
+    n_bins: usize,
+    /// How the bin edges are placed.
+    strategy: BinStrategy,
+    /// The `n_bins + 1` bin edges for each feature.
+    bin_edges: Vec<Vec<f64>>,
+    /// Indicates whether the fitter has been fit.
+    fit: FitStatus,
+    phantom: std::marker::PhantomData<Y>,
+}
+
+impl<Y> KBinsDiscretizerFitter<Y> {
+    /// Create a new instance of the KBinsDiscretizerFitter.
+    ///
+    /// #### Parameters
+    /// - n_bins: The number of bins per feature.
+    /// - strategy: How the bin edges are placed.
+    ///
+    pub fn new(n_bins: usize, strategy: BinStrategy) -> Self {
+        KBinsDiscretizerFitter {
+            n_bins,
+            strategy,
+            bin_edges: Vec::new(),
+            fit: FitStatus::NotFit,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of bins per feature.
+    pub fn n_bins(&self) -> &usize {
+        &self.n_bins
+    }
+
+    /// Returns a reference to the bin strategy.
+    pub fn strategy(&self) -> &BinStrategy {
+        &self.strategy
+    }
+
+    /// Returns a reference to the bin edges for each feature.
+    pub fn bin_edges(&self) -> &Vec<Vec<f64>> {
+        &self.bin_edges
+    }
+}
+
+impl<Y> PreprocessorFitter<Dataset<Matrix<f64>, Vector<Y>>, KBinsDiscretizer<Y>>
+    for KBinsDiscretizerFitter<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Fits the bin edges for each feature of the dataset.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped KBinsDiscretizer.
+    ///
+    fn fit(mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<KBinsDiscretizer<Y>> {
+        if self.n_bins == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                "Number of bins must be at least 1",
+            ));
+        }
+        if input.data().rows() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot fit bin edges on an empty dataset",
+            ));
+        }
+
+        let mut bin_edges = Vec::with_capacity(input.data().cols());
+        for idx in 0..input.data().cols() {
+            let sorted = sorted_values(input.data().col(idx).iter());
+            let edges = match self.strategy {
+                BinStrategy::Uniform => {
+                    let min = sorted[0];
+                    let width = (sorted[sorted.len() - 1] - min) / self.n_bins as f64;
+                    (0..=self.n_bins).map(|i| min + width * i as f64).collect()
+                }
+                BinStrategy::Quantile => (0..=self.n_bins)
+                    .map(|i| sorted_quantile(&sorted, i as f64 / self.n_bins as f64))
+                    .collect(),
+            };
+            bin_edges.push(edges);
+        }
+
+        self.bin_edges = bin_edges;
+        self.fit = FitStatus::Fit;
+        Ok(KBinsDiscretizer { fitter: self })
+    }
+
+    /// Get the fit status for the preprocessor fitter.
+    fn fit_status(&self) -> &FitStatus {
+        &self.fit
+    }
+}
@@ -0,0 +1,189 @@
+//! # Cyclical Encoder Module
+//!
+//! This module defines the cyclical encoder. The cyclical encoder
+//! replaces a periodic feature, such as the month or the hour, with
+//! its sine and cosine so that the ends of the cycle (e.g. December
+//! and January) end up next to each other.
+//!
+//! ## Examples
+//! ```
+//! use rust_ml::dataset::Dataset;
+//! use rust_ml::linalg::{BaseMatrix, Matrix, Vector};
+//! use rust_ml::preprocessing::encoders::cyclical::CyclicalEncoderFitter;
+//! use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+//!
+//! let dataset = Dataset::new(
+//!     Matrix::new(3, 2, vec![1.0, 5.0, 6.0, 7.0, 12.0, 9.0]),
+//!     Vector::new(vec![0.0, 1.0, 0.0]),
+//!     Vector::new(vec!["month".to_string(), "amount".to_string()]),
+//!     "label".to_string(),
+//! );
+//!
+//! let encoder_fitter = CyclicalEncoderFitter::new("month", 12.0);
+//! let mut encoder = encoder_fitter.fit(&dataset).unwrap();
+//! let encoded_dataset = encoder.transform(&dataset).unwrap();
+//!
+//! assert_eq!(encoder.fitter().fit_status(), &FitStatus::Fit);
+//! assert_eq!(encoded_dataset.data().cols(), 3);
+//! assert_eq!(encoded_dataset.data_columns()[1], "month_cos");
+//! ```
+
+use crate::base::error::{Error, ErrorKind};
+use crate::base::MLResult;
+use crate::dataset::Dataset;
+use crate::linalg::{BaseMatrix, Matrix, Vector};
+use crate::preprocessing::{check_columns, FitStatus, Preprocessor, PreprocessorFitter};
+use std::f64::consts::PI;
+use std::fmt::Debug;
+
+/// Struct for the Cyclical Encoder.
+#[derive(Clone, Debug)]
+pub struct CyclicalEncoder<Y> {
+    /// The fitter.
+    fitter: CyclicalEncoderFitter<Y>,
+}
+
+impl<Y> CyclicalEncoder<Y> {
+    /// Returns a reference to the fitter.
+    pub fn fitter(&self) -> &CyclicalEncoderFitter<Y> {
+        &self.fitter
+    }
+}
+
+impl<Y> Preprocessor<Dataset<Matrix<f64>, Vector<Y>>> for CyclicalEncoder<Y>
+where
+    Y: Clone + Debug,
+{
+    type O = Dataset<Matrix<f64>, Vector<Y>>;
+
+    /// Replaces the encoded column with its `{col}_sin` and `{col}_cos`
+    /// columns, in place, and returns a new Dataset struct.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to encode.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset with one more column than the input.
+    ///
+    fn transform(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<Self::O> {
+        check_columns(&self.fitter.columns, input.data_columns())?;
+        let index = self.fitter.column_index;
+
+        let mut data = Vec::with_capacity(input.data().rows() * (input.data().cols() + 1));
+        for row in input.data().row_iter() {
+            for (col, &value) in row.raw_slice().iter().enumerate() {
+                if col == index {
+                    let angle = 2.0 * PI * value / self.fitter.period;
+                    data.push(angle.sin());
+                    data.push(angle.cos());
+                } else {
+                    data.push(value);
+                }
+            }
+        }
+
+        let mut columns = Vec::with_capacity(input.data_columns().size() + 1);
+        for (col, name) in input.data_columns().iter().enumerate() {
+            if col == index {
+                columns.push(format!("{}_sin", name));
+                columns.push(format!("{}_cos", name));
+            } else {
+                columns.push(name.clone());
+            }
+        }
+
+        Ok(Dataset::new(
+            Matrix::new(input.data().rows(), columns.len(), data),
+            input.target().clone(),
+            Vector::new(columns),
+            input.target_column().to_string(),
+        ))
+    }
+}
+
+/// Struct for the fitter for the Cyclical Encoder.
+#[derive(Clone, Debug)]
+pub struct CyclicalEncoderFitter<Y> {
+    /// The name of the column to encode.
+    column: String,
+    /// The length of one cycle, e.g. 12 for months.
+    period: f64,
+    /// The feature column names seen at fit time.
+    columns: Vector<String>,
+    /// The position of the encoded column in the feature matrix.
+    column_index: usize,
+    /// Indicates whether the fitter has been fit.
+    fit: FitStatus,
+    phantom: std::marker::PhantomData<Y>,
+}
+
+impl<Y> CyclicalEncoderFitter<Y> {
+    /// Create a new instance of the CyclicalEncoderFitter.
+    ///
+    /// #### Parameters
+    /// - column: The name of the column to encode.
+    /// - period: The length of one cycle, must be positive.
+    ///
+    pub fn new(column: &str, period: f64) -> Self {
+        CyclicalEncoderFitter {
+            column: column.to_string(),
+            period,
+            columns: Vector::new(Vec::new()),
+            column_index: 0,
+            fit: FitStatus::NotFit,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns a reference to the name of the encoded column.
+    pub fn column(&self) -> &String {
+        &self.column
+    }
+
+    /// Returns the period of the cycle.
+    pub fn period(&self) -> &f64 {
+        &self.period
+    }
+}
+
+impl<Y> PreprocessorFitter<Dataset<Matrix<f64>, Vector<Y>>, CyclicalEncoder<Y>>
+    for CyclicalEncoderFitter<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Validates the period and finds the column to encode.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped CyclicalEncoder.
+    ///
+    fn fit(mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<CyclicalEncoder<Y>> {
+        if !(self.period.is_finite() && self.period > 0.0) {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!("Period must be positive, got {}", self.period),
+            ));
+        }
+        self.column_index = input
+            .data_columns()
+            .iter()
+            .position(|c| c == &self.column)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidParameters,
+                    format!("Column {} not found in dataset", self.column),
+                )
+            })?;
+
+        self.columns = input.data_columns().clone();
+        self.fit = FitStatus::Fit;
+        Ok(CyclicalEncoder { fitter: self })
+    }
+
+    /// Get the fit status for the preprocessor fitter.
+    fn fit_status(&self) -> &FitStatus {
+        &self.fit
+    }
+}
@@ -0,0 +1,215 @@
+//! # Feature Hasher Module
+//!
+//! This module defines the feature hasher. The feature hasher encodes
+//! the categorical features in a `MixedDataset` without learning the
+//! categories, each value is hashed into one of `n_features` buckets
+//! and the bucket's column is incremented. This keeps the number of
+//! columns fixed for very high cardinality features, at the cost of
+//! unrelated categories sharing a bucket when their hashes collide.
+//! The hash is FNV-1a, so the encoding is the same across runs.
+//!
+//! ## Examples
+//! ```
+//! use rust_ml::dataset::{pokemon, MixedDataset};
+//! use rust_ml::linalg::{BaseMatrix, Vector};
+//! use rust_ml::preprocessing::encoders::feature_hasher::FeatureHasherFitter;
+//! use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+//!
+//! let pokemon_dataset: MixedDataset<Vector<String>> = pokemon::load();
+//!
+//! let hasher_fitter = FeatureHasherFitter::new(8);
+//! let mut hasher = hasher_fitter.fit(&pokemon_dataset).unwrap();
+//!
+//! let pokemon_hashed_dataset = hasher.transform(&pokemon_dataset).unwrap();
+//! assert_eq!(hasher.fitter().fit_status(), &FitStatus::Fit);
+//! assert_eq!(pokemon_hashed_dataset.data().rows(), 800);
+//! assert_eq!(pokemon_hashed_dataset.data().cols(), 9 + 2 * 8);
+//! ```
+
+use crate::base::error::{Error, ErrorKind};
+use crate::base::MLResult;
+use crate::dataset::{Dataset, MixedDataValue, MixedDataset};
+use crate::linalg::{Matrix, Vector};
+use crate::preprocessing::{check_columns, FitStatus, Preprocessor, PreprocessorFitter};
+use std::fmt::Debug;
+
+/// The FNV-1a 64 bit offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+/// The FNV-1a 64 bit prime.
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Struct for the Feature Hasher.
+#[derive(Clone, Debug)]
+pub struct FeatureHasher<Y> {
+    /// The fitter.
+    fitter: FeatureHasherFitter<Y>,
+}
+
+impl<Y> FeatureHasher<Y> {
+    /// Returns a reference to the fitter.
+    pub fn fitter(&self) -> &FeatureHasherFitter<Y> {
+        &self.fitter
+    }
+}
+
+impl<Y> Preprocessor<MixedDataset<Vector<Y>>> for FeatureHasher<Y>
+where
+    Y: Clone + Debug,
+{
+    type O = Dataset<Matrix<f64>, Vector<Y>>;
+
+    /// Replaces each categorical column with its `n_features` hashed
+    /// `{col}_hash_{bucket}` columns and returns a new Dataset struct.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the MixedDataset to encode.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset struct, or an InvalidData error for a
+    ///   missing value or a value whose type does not match its column.
+    ///
+    fn transform(&mut self, input: &MixedDataset<Vector<Y>>) -> MLResult<Self::O> {
+        check_columns(&self.fitter.columns, input.data_columns())?;
+        let n_features = self.fitter.n_features;
+
+        let mut columns = Vec::new();
+        for (col, name) in input.data_columns().iter().enumerate() {
+            if self.fitter.hashed[col] {
+                columns.extend((0..n_features).map(|bucket| format!("{}_hash_{}", name, bucket)));
+            } else {
+                columns.push(name.clone());
+            }
+        }
+
+        let mut data = Vec::with_capacity(input.data().len() * columns.len());
+        for row in input.data() {
+            for (col, value) in row.iter().enumerate() {
+                match (self.fitter.hashed[col], value) {
+                    (true, MixedDataValue::Categorical(category)) => {
+                        let mut encoded = vec![0.0; n_features];
+                        encoded[(fnv1a(category) % n_features as u64) as usize] += 1.0;
+                        data.extend(encoded);
+                    }
+                    (false, MixedDataValue::Numeric(num)) => data.push(*num),
+                    _ => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!(
+                                "Cannot hash value {:?} in column {}",
+                                value,
+                                input.data_columns()[col]
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(Dataset::new(
+            Matrix::new(input.data().len(), columns.len(), data),
+            input.target().clone(),
+            Vector::new(columns),
+            input.target_column().to_string(),
+        ))
+    }
+}
+
+/// Struct for the fitter for the Feature Hasher.
+#[derive(Clone, Debug)]
+pub struct FeatureHasherFitter<Y> {
+    /// The number of hashed columns per categorical column.
+    n_features: usize,
+    /// The feature column names seen at fit time.
+    columns: Vector<String>,
+    /// Whether each column is categorical and hashed.
+    hashed: Vec<bool>,
+    /// Indicates whether the fitter has been fit.
+    fit: FitStatus,
+    phantom: std::marker::PhantomData<Y>,
+}
+
+impl<Y> FeatureHasherFitter<Y> {
+    /// Create a new instance of the FeatureHasherFitter.
+    ///
+    /// #### Parameters
+    /// - n_features: The number of hashed columns per categorical column,
+    ///   must be at least 1.
+    ///
+    pub fn new(n_features: usize) -> Self {
+        FeatureHasherFitter {
+            n_features,
+            columns: Vector::new(Vec::new()),
+            hashed: Vec::new(),
+            fit: FitStatus::NotFit,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of hashed columns per categorical column.
+    pub fn n_features(&self) -> &usize {
+        &self.n_features
+    }
+
+    /// Returns a reference to the feature column names seen at fit time.
+    pub fn columns(&self) -> &Vector<String> {
+        &self.columns
+    }
+
+    /// Returns the names of the columns that are hashed.
+    pub fn hashed_columns(&self) -> Vec<&String> {
+        self.columns
+            .iter()
+            .zip(self.hashed.iter())
+            .filter(|(_, &hashed)| hashed)
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+impl<Y> PreprocessorFitter<MixedDataset<Vector<Y>>, FeatureHasher<Y>> for FeatureHasherFitter<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Finds the categorical columns to hash, a column is categorical if any
+    /// of its values is categorical.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the MixedDataset to fit on.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped FeatureHasher.
+    ///
+    fn fit(mut self, input: &MixedDataset<Vector<Y>>) -> MLResult<FeatureHasher<Y>> {
+        if self.n_features == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                "Number of hashed features must be at least 1",
+            ));
+        }
+        self.hashed = (0..input.data_columns().size())
+            .map(|col| {
+                input
+                    .data()
+                    .iter()
+                    .any(|row| matches!(row[col], MixedDataValue::Categorical(_)))
+            })
+            .collect();
+
+        self.columns = input.data_columns().clone();
+        self.fit = FitStatus::Fit;
+        Ok(FeatureHasher { fitter: self })
+    }
+
+    /// Get the fit status for the preprocessor fitter.
+    fn fit_status(&self) -> &FitStatus {
+        &self.fit
+    }
+}
+
+/// Hashes a string with 64 bit FNV-1a, which unlike the standard library's
+/// hasher is stable across runs and platforms.
+fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
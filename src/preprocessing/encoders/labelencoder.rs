@@ -26,16 +26,21 @@
 //! assert_eq!(label_encoder.fitter().fit_status(), &FitStatus::Fit);
 //! ```
 
-use super::super::{FitStatus, Preprocessor, PreprocessorFitter};
+use super::super::{check_fit, FitStatus, Preprocessor, PreprocessorFitter};
 use crate::base::error::{Error, ErrorKind};
 use crate::base::MLResult;
-use crate::linalg::Vector;
+use crate::dataset::Dataset;
+use crate::linalg::{BaseMatrix, Matrix, Vector};
 
 use num::Float;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
 
+/// Type alias for a dataset with a label encoded target, along with the
+/// fitted encoder used to encode it.
+pub type EncodedLabelDataset<K, V> = (Dataset<Matrix<f64>, Vector<V>>, LabelEncoder<K, V>);
+
 /// Struct for the Label Encoder.
 #[derive(Clone, Debug)]
 pub struct LabelEncoder<K, V>
@@ -74,20 +79,165 @@ where
     /// - MLResult wrapped label encoded label vector.
     ///
     fn transform(&mut self, input: &Vector<K>) -> MLResult<Vector<V>> {
+        let (mapped_vec, _) = self.transform_with_indices(input)?;
+        Ok(mapped_vec)
+    }
+}
+
+impl<K, V> LabelEncoder<K, V>
+where
+    K: Clone + Debug + Eq + Hash,
+    V: Float + Clone + Debug,
+{
+    /// Transforms the Vector based on the fitted Label Encoder hash map and
+    /// the unknown label policy, also returning which input positions were kept.
+    ///
+    /// #### Parameters:
+    /// - input: A reference to the label vector.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped tuple of the label encoded vector and the indices of
+    ///   the input labels it contains. Only the `Skip` policy drops indices.
+    ///
+    pub fn transform_with_indices(
+        &mut self,
+        input: &Vector<K>,
+    ) -> MLResult<(Vector<V>, Vec<usize>)> {
         let mut mapped_vec = Vec::with_capacity(input.size());
-        for element in input {
-            let mapped_value = self.fitter.label_map.get(&element);
-            match mapped_value {
-                Some(v) => mapped_vec.push(*v),
-                None => {
+        let mut kept_indices = Vec::with_capacity(input.size());
+        for (idx, element) in input.iter().enumerate() {
+            let mapped_value = self.fitter.label_map.get(element);
+            match (mapped_value, &self.fitter.unknown_policy) {
+                (Some(v), _) => mapped_vec.push(*v),
+                (None, UnknownLabelPolicy::Assign(v)) => mapped_vec.push(*v),
+                (None, UnknownLabelPolicy::Skip) => continue,
+                (None, UnknownLabelPolicy::Error) => {
                     return Err(Error::new(
                         ErrorKind::InvalidState,
                         "Label not found in encoder, invalid fitter state.",
                     ))
                 }
             }
+            kept_indices.push(idx);
         }
-        Ok(Vector::new(mapped_vec))
+        Ok((Vector::new(mapped_vec), kept_indices))
+    }
+
+    /// Transforms the dataset's target and returns a new dataset with the
+    /// encoded target, consuming the dataset so the feature matrix is moved
+    /// rather than copied. Rows whose label is dropped by the `Skip` policy
+    /// are removed from the feature matrix as well.
+    ///
+    /// #### Parameters:
+    /// - dataset: The Dataset with the categorical target to encode.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset with the encoded target.
+    ///
+    pub fn transform_into_dataset(
+        &mut self,
+        dataset: Dataset<Matrix<f64>, Vector<K>>,
+    ) -> MLResult<Dataset<Matrix<f64>, Vector<V>>> {
+        let (data, target, data_columns, target_column) = dataset.into_parts();
+        let (encoded_target, kept_indices) = self.transform_with_indices(&target)?;
+        let data = if kept_indices.len() == data.rows() {
+            data
+        } else {
+            data.select_rows(&kept_indices)
+        };
+
+        Ok(Dataset::new(
+            data,
+            encoded_target,
+            data_columns,
+            target_column,
+        ))
+    }
+}
+
+impl<K, V> LabelEncoderFitter<K, V>
+where
+    K: Clone + Debug + Eq + Hash,
+    V: Float + Clone + Debug,
+{
+    /// Fits the label encoder on the dataset's target and encodes it, consuming
+    /// the dataset so the feature matrix is moved rather than copied.
+    ///
+    /// #### Parameters:
+    /// - dataset: The Dataset with the categorical target to encode.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped tuple of the Dataset with the encoded target and the
+    ///   fitted LabelEncoder.
+    ///
+    pub fn fit_transform_dataset(
+        self,
+        dataset: Dataset<Matrix<f64>, Vector<K>>,
+    ) -> MLResult<EncodedLabelDataset<K, V>> {
+        let mut label_encoder = self.fit(dataset.target())?;
+        let encoded_dataset = label_encoder.transform_into_dataset(dataset)?;
+        Ok((encoded_dataset, label_encoder))
+    }
+}
+
+impl<K, V> LabelEncoderFitter<K, V>
+where
+    K: Clone + Debug + Eq + Hash + Ord,
+    V: Float + Clone + Debug,
+{
+    /// Merges the vocabularies of two fitted label encoder fitters, e.g. ones
+    /// fit on different data splits. The codes of both are discarded and the
+    /// union of the labels is re-coded with contiguous codes in sorted label
+    /// order, so the result does not depend on which split saw a label first.
+    /// The unknown label policy of `self` is kept.
+    ///
+    /// #### Parameters:
+    /// - other: The other fitted fitter.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped fitted fitter with the merged label map.
+    ///
+    pub fn merge(mut self, other: &LabelEncoderFitter<K, V>) -> MLResult<LabelEncoderFitter<K, V>> {
+        check_fit(&self.fit)?;
+        check_fit(&other.fit)?;
+
+        let mut labels: Vec<K> = self
+            .label_map
+            .keys()
+            .chain(other.label_map.keys())
+            .cloned()
+            .collect();
+        labels.sort();
+        labels.dedup();
+
+        let mut code = V::zero();
+        self.label_map = labels
+            .into_iter()
+            .map(|label| {
+                let entry = (label, code);
+                code = code + V::one();
+                entry
+            })
+            .collect();
+        Ok(self)
+    }
+}
+
+/// Enum for how the encoder handles labels that were not seen during fitting.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnknownLabelPolicy<V> {
+    /// Return an error (the default).
+    Error,
+    /// Leave the unknown labels out of the transformed vector.
+    Skip,
+    /// Map all unknown labels to a fixed code.
+    Assign(V),
+}
+
+impl<V> Default for UnknownLabelPolicy<V> {
+    /// Sets the UnknownLabelPolicy enum to the default value of Error.
+    fn default() -> Self {
+        UnknownLabelPolicy::Error
     }
 }
 
@@ -100,6 +250,8 @@ where
 {
     /// The label map.
     label_map: HashMap<K, V>,
+    /// How labels not seen during fitting are transformed.
+    unknown_policy: UnknownLabelPolicy<V>,
     /// Indicates whether the fitter has been fit.
     fit: FitStatus,
 }
@@ -113,6 +265,46 @@ where
     pub fn label_map(&self) -> &HashMap<K, V> {
         &self.label_map
     }
+
+    /// Returns a reference to the unknown label policy.
+    pub fn unknown_policy(&self) -> &UnknownLabelPolicy<V> {
+        &self.unknown_policy
+    }
+
+    /// Sets how labels that were not seen during fitting are transformed.
+    ///
+    /// #### Parameters:
+    /// - policy: The UnknownLabelPolicy to use.
+    ///
+    /// #### Returns:
+    /// - The updated fitter.
+    ///
+    pub fn with_unknown(mut self, policy: UnknownLabelPolicy<V>) -> Self {
+        self.unknown_policy = policy;
+        self
+    }
+
+    /// Returns the sentinel value unknown labels are mapped to, if set.
+    pub fn nan_value(&self) -> Option<&V> {
+        match &self.unknown_policy {
+            UnknownLabelPolicy::Assign(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Maps labels that were not seen during fitting to a sentinel value, such
+    /// as `-1.0`, instead of erroring. Shorthand for
+    /// `with_unknown(UnknownLabelPolicy::Assign(value))`.
+    ///
+    /// #### Parameters:
+    /// - value: The sentinel code for unknown labels.
+    ///
+    /// #### Returns:
+    /// - The updated fitter.
+    ///
+    pub fn with_nan_value(self, value: V) -> Self {
+        self.with_unknown(UnknownLabelPolicy::Assign(value))
+    }
 }
 
 impl<K, V> Default for LabelEncoderFitter<K, V>
@@ -124,6 +316,7 @@ where
     fn default() -> Self {
         Self {
             label_map: HashMap::default(),
+            unknown_policy: UnknownLabelPolicy::default(),
             fit: FitStatus::default(),
         }
     }
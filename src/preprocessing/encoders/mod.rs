@@ -5,9 +5,17 @@
 //! ## Features
 //! - Label Encoder 
 //! - One Hot Encoder
+//! - Cyclical Encoder
+//! - Feature Hasher
 
 /// Module for the label encoder.
 pub mod labelencoder;
 
 /// Module for the one hot encoder.
 pub mod onehotencoder;
+
+/// Module for the cyclical encoder.
+pub mod cyclical;
+
+/// Module for the feature hasher.
+pub mod feature_hasher;
@@ -3,7 +3,14 @@
 //! This module defines the one hot encoder. The one hot encoder
 //! encodes all categorical features in a `MixedDataset`. The
 //! encoder will automatically determine the categories from the
-//! data.
+//! data. By default categories are indexed in the order they are
+//! first seen, `with_sorted_categories` indexes them in sorted order
+//! instead so the column order does not depend on the row order.
+//! For streaming data, `partial_fit` accumulates the categories of
+//! each batch and `finalize` produces the encoder. To chain the
+//! encoder with the numeric preprocessors, `OneHotDatasetTransform`
+//! implements `DatasetTransform` for code-valued columns of a
+//! numeric `Dataset`.
 //!
 //! ## Examples
 //! ```
@@ -22,12 +29,15 @@
 //! assert_eq!(pokemon_ohe_dataset.data().cols(), 46);
 //! ```
 
-use super::super::{FitStatus, Preprocessor, PreprocessorFitter};
+use super::super::{
+    check_columns, check_fit, DatasetTransform, FitStatus, Preprocessor, PreprocessorFitter,
+};
+use crate::base::error::{Error, ErrorKind};
 use crate::base::MLResult;
 use crate::dataset::{Dataset, MixedDataValue, MixedDataset};
-use crate::linalg::{Matrix, Vector};
+use crate::linalg::{BaseMatrix, Matrix, Vector};
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 
 /// Struct for the One Hot Encoder.
@@ -44,26 +54,126 @@ impl<Y> OneHotEncoder<Y> {
     }
 }
 
-impl<Y> Preprocessor<MixedDataset<Vector<Y>>> for OneHotEncoder<Y>
+impl<Y> OneHotEncoder<Y>
 where
     Y: Clone + Debug,
 {
-    type O = Dataset<Matrix<f64>, Vector<Y>>;
-
-    /// One hot encodes the categorical columns and returns a new Dataset struct.
-    /// 
+    /// One hot encodes the categorical columns but keeps the result a
+    /// MixedDataset, with each categorical column replaced inline by its one
+    /// hot columns as numeric cells. Unlike `transform`, which rejects missing
+    /// values, a missing value is propagated: it stays missing in a numeric
+    /// column and is missing in each one hot column of a categorical column.
+    ///
     /// #### Parameters:
     /// - input: Reference to the MixedDataset to encode.
     ///
     /// #### Returns:
-    /// - MLResult wrapped Dataset struct.
+    /// - MLResult wrapped MixedDataset with only numeric or missing cells.
     ///
-    fn transform(&mut self, input: &MixedDataset<Vector<Y>>) -> MLResult<Self::O> {
-        let mut transformed_data = Vec::new();
-        let mut new_column_names = Vec::new();
+    pub fn transform_inplace(
+        &self,
+        input: &MixedDataset<Vector<Y>>,
+    ) -> MLResult<MixedDataset<Vector<Y>>> {
+        let transformed_data = self.encode_rows(input, MixedDataValue::Numeric, |_| {
+            Ok(MixedDataValue::Missing)
+        })?;
+
+        Ok(MixedDataset::new(
+            transformed_data,
+            input.target().clone(),
+            Vector::new(self.encoded_column_names(input)),
+            input.target_column().to_string(),
+        ))
+    }
+
+    /// One hot encodes the categorical columns into a new Dataset struct,
+    /// shared by `Preprocessor::transform` and `OneHotDatasetTransform`.
+    fn encode_dataset(
+        &self,
+        input: &MixedDataset<Vector<Y>>,
+    ) -> MLResult<Dataset<Matrix<f64>, Vector<Y>>> {
+        // Missing values have no numeric representation and have to be
+        // handled before encoding.
+        let transformed_data = self.encode_rows(
+            input,
+            |num| num,
+            |col_name| {
+                Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("Cannot encode missing value in column {}", col_name),
+                ))
+            },
+        )?;
+        let new_column_names = self.encoded_column_names(input);
 
-        // Add the new one hot encoded categorical column names defined
-        // during the fitting process.
+        // Create data Matrix.
+        let row_dimension = transformed_data.len();
+        let column_dimension = new_column_names.len();
+        let flattened_data: Vec<f64> = transformed_data.into_iter().flatten().collect();
+        let data = Matrix::new(row_dimension, column_dimension, flattened_data);
+
+        Ok(Dataset::new(
+            data,
+            Vector::new(input.target().clone()),
+            Vector::new(new_column_names),
+            input.target_column().to_string().clone(),
+        ))
+    }
+
+    /// Encodes each row of the input, replacing every categorical value with
+    /// its one hot cells and passing numeric values through. The `numeric`
+    /// function builds an output cell from a number and `missing` decides what
+    /// a missing value in the named column becomes.
+    fn encode_rows<T, N, M>(
+        &self,
+        input: &MixedDataset<Vector<Y>>,
+        numeric: N,
+        missing: M,
+    ) -> MLResult<Vec<Vec<T>>>
+    where
+        T: Clone,
+        N: Fn(f64) -> T,
+        M: Fn(&str) -> MLResult<T>,
+    {
+        check_columns(&self.fitter.columns, input.data_columns())?;
+
+        let mut transformed_data = Vec::with_capacity(input.data().len());
+        for row in input.data() {
+            let mut new_row = Vec::new();
+            for (value, col_name) in row.iter().zip(input.data_columns().iter()) {
+                match (self.fitter.category_map.get(col_name), value) {
+                    // Initialize the zero-filled one hot cells for the column and
+                    // set the category's index to 1. Categories not seen at fit
+                    // time are all zeros.
+                    (Some(map), MixedDataValue::Categorical(val)) => {
+                        let mut encoded = vec![numeric(0.0); map.len()];
+                        if let Some(&index) = map.get(val) {
+                            encoded[index] = numeric(1.0);
+                        }
+                        new_row.extend(encoded);
+                    }
+                    (Some(map), MixedDataValue::Missing) => {
+                        new_row.extend(vec![missing(col_name)?; map.len()]);
+                    }
+                    (_, MixedDataValue::Numeric(num)) => new_row.push(numeric(*num)),
+                    (None, MixedDataValue::Missing) => new_row.push(missing(col_name)?),
+                    (None, MixedDataValue::Categorical(_)) => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!("Column {} had no categories at fit time", col_name),
+                        ));
+                    }
+                }
+            }
+            transformed_data.push(new_row);
+        }
+        Ok(transformed_data)
+    }
+
+    /// Builds the column names of the encoded data, replacing each categorical
+    /// column with one `{column}_{category}` column per category.
+    fn encoded_column_names(&self, input: &MixedDataset<Vector<Y>>) -> Vec<String> {
+        let mut new_column_names = Vec::new();
         for col_name in input.data_columns().iter() {
             if let Some(map) = self.fitter.category_map.get(col_name) {
                 // Make sure one hot encoded column names are in the right order.
@@ -76,48 +186,143 @@ where
                 new_column_names.push(col_name.clone());
             }
         }
+        new_column_names
+    }
+}
 
-        // Handle the data transformation.
-        for row in input.data() {
-            let mut new_row = Vec::new();
-            for (col_index, value) in row.iter().enumerate() {
-                let col_name = &input.data_columns()[col_index];
-                match value {
-                    // For categorical values, look up the encoding map for the
-                    // column and initialize the zero-filled vector of the
-                    // appropriate length. Then set the corresponding index
-                    // to 1 for the one hot encoded binary value.
-                    MixedDataValue::Categorical(val) => {
-                        if let Some(map) = self.fitter.category_map.get(col_name) {
-                            let mut encoded = vec![0.0; map.len()];
-                            if let Some(&index) = map.get(val) {
-                                encoded[index] = 1.0;
-                            }
-                            new_row.extend(encoded);
-                        }
-                    }
-                    // For numerical values, dereference the number value and add
-                    // it to the row as is.
-                    MixedDataValue::Numeric(num) => {
-                        new_row.push(*num);
-                    }
-                }
-            }
-            transformed_data.push(new_row);
+impl<Y> Preprocessor<MixedDataset<Vector<Y>>> for OneHotEncoder<Y>
+where
+    Y: Clone + Debug,
+{
+    type O = Dataset<Matrix<f64>, Vector<Y>>;
+
+    /// One hot encodes the categorical columns and returns a new Dataset struct.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the MixedDataset to encode.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset struct, or an InvalidData error for a
+    ///   missing value.
+    ///
+    fn transform(&mut self, input: &MixedDataset<Vector<Y>>) -> MLResult<Self::O> {
+        self.encode_dataset(input)
+    }
+}
+
+/// Adapts the one hot encoder to `DatasetTransform` so it can be chained with
+/// the numeric preprocessors. The named columns of the numeric Dataset hold
+/// category codes, on both fit and transform they are converted to the
+/// categorical columns of a MixedDataset and encoded with the wrapped encoder.
+#[derive(Clone, Debug)]
+pub struct OneHotDatasetTransform<Y> {
+    /// The names of the numeric columns to encode as categories.
+    columns: Vec<String>,
+    /// The encoder, fit on the converted dataset.
+    encoder: OneHotEncoder<Y>,
+}
+
+impl<Y> OneHotDatasetTransform<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Creates an adapter that has not been fit yet.
+    ///
+    /// #### Parameters:
+    /// - columns: The names of the numeric columns to encode as categories.
+    ///
+    pub fn new(columns: &[&str]) -> Self {
+        OneHotDatasetTransform {
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            encoder: OneHotEncoder {
+                fitter: OneHotEncoderFitter::default(),
+            },
         }
+    }
 
-        // Create data Matrix.
-        let row_dimension = transformed_data.len();
-        let column_dimension = transformed_data[0].len();
-        let flattened_data: Vec<f64> = transformed_data.into_iter().flatten().collect();
-        let data = Matrix::new(row_dimension, column_dimension, flattened_data);
+    /// Sets whether the categories of each column are indexed in sorted order
+    /// during fitting, see `OneHotEncoderFitter::with_sorted_categories`.
+    ///
+    /// #### Parameters:
+    /// - sorted: Whether to sort the categories.
+    ///
+    /// #### Returns:
+    /// - The updated adapter.
+    ///
+    pub fn with_sorted_categories(mut self, sorted: bool) -> Self {
+        self.encoder.fitter.sorted_categories = sorted;
+        self
+    }
 
-        Ok(Dataset::new(
+    /// Returns a reference to the wrapped encoder.
+    pub fn encoder(&self) -> &OneHotEncoder<Y> {
+        &self.encoder
+    }
+
+    /// Converts a numeric Dataset into a MixedDataset with the named columns
+    /// re-typed as categorical.
+    fn to_mixed(
+        &self,
+        input: &Dataset<Matrix<f64>, Vector<Y>>,
+    ) -> MLResult<MixedDataset<Vector<Y>>> {
+        let data = input
+            .data()
+            .row_iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&value| MixedDataValue::Numeric(value))
+                    .collect()
+            })
+            .collect();
+        let mut mixed = MixedDataset::new(
             data,
-            Vector::new(input.target().clone()),
-            Vector::new(new_column_names),
-            input.target_column().to_string().clone(),
-        ))
+            input.target().clone(),
+            input.data_columns().clone(),
+            input.target_column().to_string(),
+        );
+        for column in self.columns.iter() {
+            mixed = mixed.set_column_categorical(column)?;
+        }
+        Ok(mixed)
+    }
+}
+
+impl<Y> DatasetTransform<Y> for OneHotDatasetTransform<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Refits the categories of the named columns on a given dataset, keeping
+    /// the columns and the category order setting.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - Empty MLResult, or an InvalidParameters error if a named column is
+    ///   not in the dataset.
+    ///
+    fn fit(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<()> {
+        let fitter = OneHotEncoderFitter::default()
+            .with_sorted_categories(self.encoder.fitter.sorted_categories);
+        self.encoder = fitter.fit(&self.to_mixed(input)?)?;
+        Ok(())
+    }
+
+    /// Replaces each named column with its one hot columns and returns a new
+    /// Dataset struct.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to encode.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped encoded Dataset.
+    ///
+    fn transform(
+        &self,
+        input: &Dataset<Matrix<f64>, Vector<Y>>,
+    ) -> MLResult<Dataset<Matrix<f64>, Vector<Y>>> {
+        check_fit(&self.encoder.fitter.fit)?;
+        self.encoder.encode_dataset(&self.to_mixed(input)?)
     }
 }
 
@@ -126,6 +331,10 @@ where
 pub struct OneHotEncoderFitter<Y> {
     /// Holds the categories found in the columns to be encoded.
     category_map: HashMap<String, HashMap<String, usize>>,
+    /// The feature column names seen at fit time.
+    columns: Vector<String>,
+    /// Whether categories are indexed in sorted rather than first-seen order.
+    sorted_categories: bool,
     /// Indicates whether the fitter has been fit.
     fit: FitStatus,
     phantom: std::marker::PhantomData<Y>,
@@ -139,6 +348,132 @@ where
     pub fn category_map(&self) -> &HashMap<String, HashMap<String, usize>> {
         &self.category_map
     }
+
+    /// Returns a reference to the feature column names seen at fit time.
+    pub fn columns(&self) -> &Vector<String> {
+        &self.columns
+    }
+
+    /// Serializes the category map to a JSON string with sorted keys so the
+    /// output is deterministic, which is useful for inspecting the encoding.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped JSON string mapping each column name to its
+    ///   category indices.
+    ///
+    pub fn category_map_json(&self) -> MLResult<String> {
+        let sorted_map: BTreeMap<&String, BTreeMap<&String, &usize>> = self
+            .category_map
+            .iter()
+            .map(|(column, map)| (column, map.iter().collect()))
+            .collect();
+        serde_json::to_string(&sorted_map).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Failed to serialize category map: {}", e),
+            )
+        })
+    }
+
+    /// Returns whether categories are indexed in sorted order.
+    pub fn sorted_categories(&self) -> bool {
+        self.sorted_categories
+    }
+
+    /// Sets whether the categories of each column are indexed in sorted order
+    /// during fitting, giving a column order that does not depend on the row
+    /// order of the data.
+    ///
+    /// #### Parameters:
+    /// - sorted: Whether to sort the categories.
+    ///
+    /// #### Returns:
+    /// - The updated fitter.
+    ///
+    pub fn with_sorted_categories(mut self, sorted: bool) -> Self {
+        self.sorted_categories = sorted;
+        self
+    }
+}
+
+impl<Y> OneHotEncoderFitter<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Incrementally fits the encoder on one batch of a stream of data. New
+    /// categories are appended after the ones seen in earlier batches, so
+    /// existing category indices never change, unless `with_sorted_categories`
+    /// is set: then `finalize` re-sorts all the categories, and a category
+    /// sorting before the ones already seen shifts their indices. Call
+    /// `finalize` once all the batches have been seen.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the MixedDataset batch.
+    ///
+    /// #### Returns:
+    /// - Empty MLResult or an InvalidState error if the batch's columns do not
+    ///   match the earlier batches.
+    ///
+    pub fn partial_fit(&mut self, input: &MixedDataset<Vector<Y>>) -> MLResult<()> {
+        if self.columns.size() == 0 {
+            self.columns = input.data_columns().clone();
+        } else {
+            check_columns(&self.columns, input.data_columns())?;
+        }
+        self.add_categories(input);
+        Ok(())
+    }
+
+    /// Finishes an incremental fit and produces the encoder.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped OneHotEncoder, or an InvalidState error if
+    ///   `partial_fit` was never called.
+    ///
+    pub fn finalize(self) -> MLResult<OneHotEncoder<Y>> {
+        if self.columns.size() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidState,
+                "partial_fit must be called at least once before finalize",
+            ));
+        }
+        self.finish_fit()
+    }
+
+    /// Adds the categories in the input's categorical columns to the category
+    /// map, giving each new category the next free index of its column.
+    fn add_categories(&mut self, input: &MixedDataset<Vector<Y>>) {
+        for (col_index, col_name) in input.data_columns().iter().enumerate() {
+            for row in input.data() {
+                // On each row, match on the column value to check if it is categorical.
+                if let MixedDataValue::Categorical(value) = &row[col_index] {
+                    // If categorical, capture value as a category in the current column map.
+                    let map = self.category_map.entry(col_name.clone()).or_default();
+                    let index = map.len();
+                    map.entry(value.clone()).or_insert(index);
+                }
+            }
+        }
+    }
+
+    /// Reindexes the categories in sorted order if requested and marks the
+    /// fitter as fit.
+    fn finish_fit(mut self) -> MLResult<OneHotEncoder<Y>> {
+        if self.sorted_categories {
+            for map in self.category_map.values_mut() {
+                let mut categories: Vec<String> = std::mem::take(map).into_keys().collect();
+                categories.sort();
+                map.extend(
+                    categories
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, category)| (category, index)),
+                );
+            }
+        }
+        self.fit = FitStatus::Fit;
+        Ok(OneHotEncoder { fitter: self })
+    }
 }
 
 impl<Y> Default for OneHotEncoderFitter<Y> {
@@ -146,6 +481,8 @@ impl<Y> Default for OneHotEncoderFitter<Y> {
     fn default() -> Self {
         Self {
             category_map: HashMap::default(),
+            columns: Vector::new(Vec::new()),
+            sorted_categories: false,
             fit: FitStatus::default(),
             phantom: std::marker::PhantomData,
         }
@@ -166,29 +503,9 @@ where
     ///
     fn fit(mut self, input: &MixedDataset<Vector<Y>>) -> MLResult<OneHotEncoder<Y>> {
         self.category_map.clear();
-        let mut category_map = HashMap::new();
-
-        for (col_index, col_name) in input.data_columns().iter().enumerate() {
-            // Initialize a hashmap for current column that will store
-            // mapping from categorical value to their indices.
-            let mut map = HashMap::new();
-
-            for row in input.data() {
-                // On each row, match on the column value to check if it is categorical.
-                if let MixedDataValue::Categorical(value) = &row[col_index] {
-                    // If categorical, capture value as a category in the current column map.
-                    let index = map.len();
-                    map.entry(value.clone()).or_insert_with(|| index);
-                }
-            }
-            // Insert the column map into the fitter category map.
-            if !map.is_empty() {
-                category_map.insert(col_name.clone(), map);
-            }
-        }
-        self.fit = FitStatus::Fit;
-        self.category_map = category_map;
-        Ok(OneHotEncoder { fitter: self })
+        self.columns = input.data_columns().clone();
+        self.add_categories(input);
+        self.finish_fit()
     }
 
     /// Get the fit status for the preprocessor fitter.
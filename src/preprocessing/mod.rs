@@ -7,14 +7,36 @@
 //! Encoders:
 //! - Label Encoder
 //! - One Hot Encoder
+//! - Cyclical Encoder
+//! - Feature Hasher
 //!
 //! Scalers:
 //! - MinMax Scaler
+//!
+//! Outliers:
+//! - Winsorizer
+//!
+//! Discretizers:
+//! - KBins Discretizer
+//!
+//! Decomposition:
+//! - Linear Discriminant Analysis
+//!
+//! Feature selection:
+//! - Select K Best
 
+use crate::base::error::{Error, ErrorKind};
 use crate::base::MLResult;
+use crate::dataset::Dataset;
+use crate::linalg::{Matrix, Vector};
+use std::fmt::Debug;
 
+pub mod decomposition;
+pub mod discretization;
 pub mod encoders;
 pub mod scalers;
+pub mod selection;
+pub mod winsorize;
 
 /// Trait for a preprocessor.
 pub trait Preprocessor<I> {
@@ -37,6 +59,41 @@ pub trait PreprocessorFitter<I, O: Preprocessor<I>> {
 
 }
 
+/// Object safe trait for preprocessors that map a numeric Dataset to another
+/// numeric Dataset. Unlike `Preprocessor` it has no associated output type, so
+/// different preprocessors can be chained in a `Vec<Box<dyn DatasetTransform<Y>>>`.
+///
+/// ## Examples
+/// ```
+/// use rust_ml::dataset::iris;
+/// use rust_ml::preprocessing::scalers::minmaxscaler::{MinMaxFitter, MinMaxScaler};
+/// use rust_ml::preprocessing::winsorize::{Winsorizer, WinsorizerFitter};
+/// use rust_ml::preprocessing::DatasetTransform;
+///
+/// let iris_dataset = iris::load();
+///
+/// let mut pipeline: Vec<Box<dyn DatasetTransform<String>>> = vec![
+///     Box::new(Winsorizer::unfit_from(WinsorizerFitter::default())),
+///     Box::new(MinMaxScaler::unfit_from(MinMaxFitter::default())),
+/// ];
+///
+/// let mut dataset = iris_dataset.clone();
+/// for step in pipeline.iter_mut() {
+///     step.fit(&dataset).unwrap();
+///     dataset = step.transform(&dataset).unwrap();
+/// }
+/// ```
+pub trait DatasetTransform<Y: Clone + Debug> {
+    /// Fit the preprocessor to the dataset, replacing any previous fit.
+    fn fit(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<()>;
+
+    /// Transform the dataset with the fitted preprocessor.
+    fn transform(
+        &self,
+        input: &Dataset<Matrix<f64>, Vector<Y>>,
+    ) -> MLResult<Dataset<Matrix<f64>, Vector<Y>>>;
+}
+
 /// Enum for the fit status.
 #[derive(Clone, Debug, PartialEq)]
 pub enum FitStatus {
@@ -52,3 +109,56 @@ impl Default for FitStatus {
        FitStatus::NotFit 
     }
 }
+
+/// Helper function that makes sure a preprocessor has been fit before it is
+/// used to transform data.
+///
+/// #### Parameters:
+/// - fit: The fit status of the preprocessor's fitter.
+///
+/// #### Returns:
+/// - Empty MLResult or an UntrainedModel error.
+///
+pub(crate) fn check_fit(fit: &FitStatus) -> MLResult<()> {
+    match fit {
+        FitStatus::Fit => Ok(()),
+        FitStatus::NotFit => Err(Error::new(
+            ErrorKind::UntrainedModel,
+            "Preprocessor must be fit before transforming data",
+        )),
+    }
+}
+
+/// Helper function that makes sure a dataset has the same feature columns, in
+/// the same order, as the dataset the preprocessor was fit on.
+///
+/// #### Parameters:
+/// - fitted: The column names seen at fit time.
+/// - input: The column names of the dataset being transformed.
+///
+/// #### Returns:
+/// - Empty MLResult or an InvalidState error describing the mismatch.
+///
+pub(crate) fn check_columns(fitted: &Vector<String>, input: &Vector<String>) -> MLResult<()> {
+    if fitted == input {
+        return Ok(());
+    }
+    let message = if fitted.size() != input.size() {
+        format!(
+            "Fitter's number of features ({}) does not match dataset's number of features ({})",
+            fitted.size(),
+            input.size()
+        )
+    } else {
+        let idx = fitted
+            .iter()
+            .zip(input.iter())
+            .position(|(f, i)| f != i)
+            .unwrap_or_default();
+        format!(
+            "Dataset column {} at position {} does not match the fitted column {}",
+            input[idx], idx, fitted[idx]
+        )
+    };
+    Err(Error::new(ErrorKind::InvalidState, message))
+}
@@ -1,6 +1,8 @@
 //! # MinMax Scaler
 //!
-//! This module creates the implementation for a basic MinMax scaler.
+//! This module creates the implementation for a basic MinMax scaler. A one
+//! dimensional variant for scaling a target vector, and mapping predictions
+//! back, is created with `MinMaxFitter::fit_vector`.
 //!
 //! ## Examples
 //! ```
@@ -18,10 +20,14 @@
 //! ```
 
 use crate::base::error::{Error, ErrorKind};
+use crate::base::stats::{sorted_quantile, sorted_values};
 use crate::base::MLResult;
 use crate::dataset::Dataset;
 use crate::linalg::{BaseMatrix, Matrix, Vector};
-use crate::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+use crate::preprocessing::{
+    check_columns, check_fit, DatasetTransform, FitStatus, Preprocessor, PreprocessorFitter,
+};
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 /// Struct for a MinMax scaler.
@@ -32,52 +38,172 @@ pub struct MinMaxScaler<Y> {
 }
 
 impl<Y> MinMaxScaler<Y> {
+    /// Creates a scaler that has not been fit yet from the fitter's settings, to
+    /// be fit later through `DatasetTransform`. Any feature ranges the fitter
+    /// was already fit with are discarded.
+    ///
+    /// #### Parameters:
+    /// - fitter: The fitter holding the scaled range and quantile range.
+    ///
+    pub fn unfit_from(fitter: MinMaxFitter<Y>) -> Self {
+        MinMaxScaler {
+            fitter: fitter.unfit(),
+        }
+    }
+
     /// Returns a reference to the fitter.
     pub fn fitter(&self) -> &MinMaxFitter<Y> {
         &self.fitter
     }
-}
 
-impl<Y> Preprocessor<Dataset<Matrix<f64>, Vector<Y>>> for MinMaxScaler<Y>
-where
-    Y: Clone + Debug,
-{
-    type O = Dataset<Matrix<f64>, Vector<Y>>;
-
-    /// Scales the features into the scaled min and max range and returns
-    /// a new Dataset struct.
+    /// Scales a bare feature matrix into the scaled min and max range. The
+    /// columns must be in the same order as the dataset the scaler was fit on.
     ///
     /// #### Parameters:
-    /// - input: Reference to the Dataset to scale.
+    /// - m: Reference to the Matrix to scale.
     ///
     /// #### Returns:
-    /// - MLResults wrapped scaled Dataset.
+    /// - MLResult wrapped scaled Matrix, or an InvalidState error if a stored
+    ///   scale factor is not finite, e.g. from params with a zero range.
     ///
-    fn transform(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<Self::O> {
+    pub fn transform_matrix(&self, m: &Matrix<f64>) -> MLResult<Matrix<f64>> {
         let fitter = self.fitter();
+        check_fit(&fitter.fit)?;
         let num_features = fitter.num_features();
-        if num_features != &input.data_columns().size() {
+        if m.cols() != *num_features {
             return Err(Error::new(
                 ErrorKind::InvalidState,
                 format!(
-                    "Fitter's number of features ({}) does not match dataset's number of features ({})",
+                    "Fitter's number of features ({}) does not match matrix's number of columns ({})",
                     num_features,
-                    input.data_columns().size()
+                    m.cols()
+                ),
+            ));
+        }
+        if let Some(idx) = fitter.scale_factors().iter().position(|f| !f.is_finite()) {
+            return Err(Error::new(
+                ErrorKind::InvalidState,
+                format!(
+                    "Scale factor of feature {} ({}) is not finite ({})",
+                    idx,
+                    fitter.columns()[idx],
+                    fitter.scale_factors()[idx]
                 ),
             ));
         }
-        let num_rows = input.data().rows();
-        let mut scaled_data = Vec::with_capacity(input.data().data().len());
+        let mut scaled_data = Vec::with_capacity(m.data().len());
+        let clamped: Vec<bool> = (0..*num_features)
+            .map(|idx| fitter.quantile_range.is_some() && fitter.is_scaled(idx))
+            .collect();
 
-        for row in input.data().row_iter() {
+        for row in m.row_iter() {
             for (idx, &value) in row.iter().enumerate() {
-                let scaled_value =
+                let mut scaled_value =
                     value * fitter.scale_factors()[idx] + fitter.constant_factors()[idx];
+                if clamped[idx] {
+                    scaled_value = scaled_value.max(fitter.scaled_min).min(fitter.scaled_max);
+                }
                 scaled_data.push(scaled_value);
             }
         }
 
-        let scaled_matrix = Matrix::new(num_rows, *num_features, scaled_data);
+        Ok(Matrix::new(m.rows(), *num_features, scaled_data))
+    }
+
+    /// Builds a human readable table of the fitted parameters, with one line
+    /// per feature listing its min and max values.
+    ///
+    /// #### Returns:
+    /// - The summary string.
+    ///
+    pub fn summary(&self) -> String {
+        let fitter = self.fitter();
+        let name_width = fitter
+            .columns()
+            .iter()
+            .map(|c| c.len())
+            .chain(std::iter::once("feature".len()))
+            .max()
+            .unwrap_or_default();
+
+        let mut summary = format!(
+            "MinMaxScaler ({:?}), scaled range [{}, {}]\n",
+            fitter.fit, fitter.scaled_min, fitter.scaled_max
+        );
+        summary.push_str(&format!(
+            "{:<width$}  {:>12}  {:>12}\n",
+            "feature",
+            "min",
+            "max",
+            width = name_width
+        ));
+        for (idx, column) in fitter.columns().iter().enumerate() {
+            summary.push_str(&format!(
+                "{:<width$}  {:>12}  {:>12}\n",
+                column,
+                fitter.min_values()[idx],
+                fitter.max_values()[idx],
+                width = name_width
+            ));
+        }
+        summary
+    }
+}
+
+impl<Y> Preprocessor<Dataset<Matrix<f64>, Vector<Y>>> for MinMaxScaler<Y>
+where
+    Y: Clone + Debug,
+{
+    type O = Dataset<Matrix<f64>, Vector<Y>>;
+
+    /// Scales the features into the scaled min and max range and returns
+    /// a new Dataset struct.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to scale.
+    ///
+    /// #### Returns:
+    /// - MLResults wrapped scaled Dataset.
+    ///
+    fn transform(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<Self::O> {
+        DatasetTransform::transform(self, input)
+    }
+}
+
+impl<Y> DatasetTransform<Y> for MinMaxScaler<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Refits the scaler on a given dataset, keeping the fitter's settings.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - Empty MLResult.
+    ///
+    fn fit(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<()> {
+        *self = self.fitter.unfit().fit(input)?;
+        Ok(())
+    }
+
+    /// Scales the features into the scaled min and max range and returns
+    /// a new Dataset struct.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to scale.
+    ///
+    /// #### Returns:
+    /// - MLResults wrapped scaled Dataset.
+    ///
+    fn transform(
+        &self,
+        input: &Dataset<Matrix<f64>, Vector<Y>>,
+    ) -> MLResult<Dataset<Matrix<f64>, Vector<Y>>> {
+        check_fit(&self.fitter.fit)?;
+        check_columns(self.fitter.columns(), input.data_columns())?;
+        let scaled_matrix = self.transform_matrix(input.data())?;
+
         Ok(Dataset::new(
             scaled_matrix,
             input.target().clone(),
@@ -87,18 +213,176 @@ where
     }
 }
 
+/// Struct for a one dimensional MinMax scaler, fit on a single vector such as
+/// a regression target with `MinMaxFitter::fit_vector`.
+#[derive(Clone, Debug)]
+pub struct MinMaxScaler1D {
+    /// The minimum value (the lower quantile value when a quantile range was
+    /// set).
+    min_value: f64,
+    /// The maximum value (the upper quantile value when a quantile range was
+    /// set).
+    max_value: f64,
+    /// The range minimum to scale by.
+    scaled_min: f64,
+    /// The range maximum to scale by.
+    scaled_max: f64,
+    /// Whether transformed values are clamped to the scaled range.
+    clamp: bool,
+    /// Scale factor, a = (scaled_max - scaled_min) / (max - min).
+    scale_factor: f64,
+    /// Constant factor, b = scaled_min - min * scale_factor.
+    constant_factor: f64,
+}
+
+impl MinMaxScaler1D {
+    /// Returns a tuple of the fitted min and max values.
+    pub fn min_max_values(&self) -> (f64, f64) {
+        (self.min_value, self.max_value)
+    }
+
+    /// Returns a tuple of the scaled_min and scaled_max.
+    pub fn min_max(&self) -> (f64, f64) {
+        (self.scaled_min, self.scaled_max)
+    }
+
+    /// Scales a vector into the scaled min and max range.
+    ///
+    /// #### Parameters:
+    /// - v: Reference to the Vector to scale.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped scaled Vector.
+    ///
+    pub fn transform(&self, v: &Vector<f64>) -> MLResult<Vector<f64>> {
+        Ok(v.clone().apply(&|value| {
+            let scaled_value = value * self.scale_factor + self.constant_factor;
+            if self.clamp {
+                scaled_value.max(self.scaled_min).min(self.scaled_max)
+            } else {
+                scaled_value
+            }
+        }))
+    }
+
+    /// Maps scaled values, such as predictions made in the scaled space, back
+    /// to the original range.
+    ///
+    /// #### Parameters:
+    /// - scaled: Reference to the scaled Vector.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Vector in the original range.
+    ///
+    pub fn inverse_transform(&self, scaled: &Vector<f64>) -> MLResult<Vector<f64>> {
+        Ok(scaled
+            .clone()
+            .apply(&|value| (value - self.constant_factor) / self.scale_factor))
+    }
+}
+
+/// Struct for the fitted parameters of a MinMax scaler, decoupled from the
+/// scaler so they can be saved and later turned back into a scaler with
+/// `MinMaxFitter::from_params`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MinMaxParams {
+    /// The feature column names seen at fit time.
+    feature_names: Vec<String>,
+    /// The minimum value for each feature.
+    min_values: Vec<f64>,
+    /// The maximum value for each feature.
+    max_values: Vec<f64>,
+    /// The scaled minimum and maximum.
+    scaled_range: (f64, f64),
+    /// The quantile range used for fitting, if one was set.
+    quantile_range: Option<(f64, f64)>,
+    /// The columns that are scaled, if only a subset is. The other columns
+    /// pass through unchanged.
+    #[serde(default)]
+    scaled_columns: Option<Vec<String>>,
+}
+
+impl MinMaxParams {
+    /// Create a new instance of the MinMaxParams.
+    ///
+    /// #### Parameters
+    /// - feature_names: The feature column names.
+    /// - min_values: The minimum value for each feature.
+    /// - max_values: The maximum value for each feature.
+    /// - scaled_range: The scaled minimum and maximum.
+    /// - quantile_range: The quantile range the values were fit on, if any.
+    ///   When set, transformed values are clamped to the scaled range.
+    ///
+    pub fn new(
+        feature_names: Vec<String>,
+        min_values: Vec<f64>,
+        max_values: Vec<f64>,
+        scaled_range: (f64, f64),
+        quantile_range: Option<(f64, f64)>,
+    ) -> Self {
+        MinMaxParams {
+            feature_names,
+            min_values,
+            max_values,
+            scaled_range,
+            quantile_range,
+            scaled_columns: None,
+        }
+    }
+
+    /// Returns a reference to the feature column names.
+    pub fn feature_names(&self) -> &Vec<String> {
+        &self.feature_names
+    }
+
+    /// Returns a reference to the minimum value of each feature.
+    pub fn min_values(&self) -> &Vec<f64> {
+        &self.min_values
+    }
+
+    /// Returns a reference to the maximum value of each feature.
+    pub fn max_values(&self) -> &Vec<f64> {
+        &self.max_values
+    }
+
+    /// Returns the scaled minimum and maximum.
+    pub fn scaled_range(&self) -> (f64, f64) {
+        self.scaled_range
+    }
+
+    /// Returns the quantile range the values were fit on, if one was set.
+    pub fn quantile_range(&self) -> Option<(f64, f64)> {
+        self.quantile_range
+    }
+
+    /// Returns the scaled columns, if only a subset of the columns is scaled.
+    pub fn scaled_columns(&self) -> Option<&Vec<String>> {
+        self.scaled_columns.as_ref()
+    }
+}
+
 /// Struct for the fitter for the MinMax Scaler.
 #[derive(Debug)]
 pub struct MinMaxFitter<Y> {
     /// The number of features in the dataset.
     num_featues: usize,
+    /// The feature column names seen at fit time.
+    columns: Vector<String>,
     /// The range minimum to scale by.
     scaled_min: f64,
     /// The range maximum to scale by.
     scaled_max: f64,
-    /// The minimum value for each feature.
+    /// Optional lower and upper quantiles used as the effective feature range
+    /// instead of the absolute min and max.
+    quantile_range: Option<(f64, f64)>,
+    /// Optional subset of the columns to scale, the other columns pass
+    /// through unchanged.
+    scaled_columns: Option<Vec<String>>,
+    /// The minimum value for each feature (the lower quantile value when a
+    /// quantile range is set).
     min_values: Vec<f64>,
-    /// The maximum value for each feature.
+    /// The maximum value for each feature (the upper quantile value when a
+    /// quantile range is set).
     max_values: Vec<f64>,
     /// Scale factor for each feature. Used to adjust the range of
     /// the original data to the scaled range. Calculated with the
@@ -128,8 +412,11 @@ impl<Y> MinMaxFitter<Y> {
     pub fn new(min: f64, max: f64) -> Self {
         MinMaxFitter {
             num_featues: 0,
+            columns: Vector::new(Vec::new()),
             scaled_min: min,
             scaled_max: max,
+            quantile_range: None,
+            scaled_columns: None,
             min_values: Vec::new(),
             max_values: Vec::new(),
             scale_factors: Vec::new(),
@@ -139,11 +426,206 @@ impl<Y> MinMaxFitter<Y> {
         }
     }
 
+    /// Fits the feature range on the given quantiles instead of the absolute
+    /// min and max, so a few outliers don't squeeze the bulk of the values.
+    /// Transformed values beyond the quantiles are clamped to the scaled range.
+    ///
+    /// #### Parameters:
+    /// - lower: The lower quantile as a fraction (e.g. 0.05).
+    /// - upper: The upper quantile as a fraction (e.g. 0.95).
+    ///
+    /// #### Returns:
+    /// - The updated fitter.
+    ///
+    pub fn with_quantile_range(mut self, lower: f64, upper: f64) -> Self {
+        self.quantile_range = Some((lower, upper));
+        self
+    }
+
+    /// Returns the quantile range used for fitting, if one is set.
+    pub fn quantile_range(&self) -> Option<(f64, f64)> {
+        self.quantile_range
+    }
+
+    /// Returns an unfit copy of the fitter with the same settings.
+    fn unfit(&self) -> Self {
+        MinMaxFitter {
+            quantile_range: self.quantile_range,
+            scaled_columns: self.scaled_columns.clone(),
+            ..MinMaxFitter::new(self.scaled_min, self.scaled_max)
+        }
+    }
+
+    /// Extracts the fitted parameters of the fitter.
+    ///
+    /// #### Returns:
+    /// - The MinMaxParams struct.
+    ///
+    pub fn params(&self) -> MinMaxParams {
+        MinMaxParams {
+            feature_names: self.columns.iter().cloned().collect(),
+            min_values: self.min_values.clone(),
+            max_values: self.max_values.clone(),
+            scaled_range: (self.scaled_min, self.scaled_max),
+            quantile_range: self.quantile_range,
+            scaled_columns: self.scaled_columns.clone(),
+        }
+    }
+
+    /// Reconstructs a ready to use scaler from previously extracted parameters
+    /// without refitting.
+    ///
+    /// #### Parameters:
+    /// - params: The fitted parameters.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped MinMaxScaler, or an InvalidParameters error if the
+    ///   number of feature names, minimums and maximums differ or a scaled
+    ///   column is not one of the features.
+    ///
+    pub fn from_params(params: MinMaxParams) -> MLResult<MinMaxScaler<Y>> {
+        let num_features = params.feature_names.len();
+        if params.min_values.len() != num_features || params.max_values.len() != num_features {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!(
+                    "Expected {} minimum and maximum values, got {} and {}",
+                    num_features,
+                    params.min_values.len(),
+                    params.max_values.len()
+                ),
+            ));
+        }
+
+        if let Some(scaled_columns) = &params.scaled_columns {
+            check_scaled_columns(scaled_columns, &params.feature_names)?;
+        }
+
+        let (scaled_min, scaled_max) = params.scaled_range;
+        let mut fitter = MinMaxFitter::new(scaled_min, scaled_max);
+        fitter.quantile_range = params.quantile_range;
+        fitter.scaled_columns = params.scaled_columns;
+        fitter.num_featues = num_features;
+        fitter.columns = Vector::new(params.feature_names);
+        fitter.set_factors(params.min_values, params.max_values);
+        fitter.fit = FitStatus::Fit;
+        Ok(MinMaxScaler { fitter })
+    }
+
+    /// Fits a one dimensional scaler on a single vector, e.g. a regression
+    /// target, so that scaled predictions can be mapped back with
+    /// `MinMaxScaler1D::inverse_transform`.
+    ///
+    /// #### Parameters:
+    /// - v: Reference to the Vector to fit on.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped MinMaxScaler1D, or an InvalidData error if the vector
+    ///   is empty or its (quantile) range is zero.
+    ///
+    pub fn fit_vector(self, v: &Vector<f64>) -> MLResult<MinMaxScaler1D> {
+        self.check_quantile_range()?;
+        if v.size() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot fit a scaler on an empty vector",
+            ));
+        }
+        let sorted = sorted_values(v.iter());
+        let (lower, upper) = self.quantile_range.unwrap_or((0.0, 1.0));
+        let min_value = sorted_quantile(&sorted, lower);
+        let max_value = sorted_quantile(&sorted, upper);
+        if max_value == min_value {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot scale a vector with a zero value range",
+            ));
+        }
+
+        let scale_factor = (self.scaled_max - self.scaled_min) / (max_value - min_value);
+        Ok(MinMaxScaler1D {
+            min_value,
+            max_value,
+            scaled_min: self.scaled_min,
+            scaled_max: self.scaled_max,
+            clamp: self.quantile_range.is_some(),
+            scale_factor,
+            constant_factor: self.scaled_min - (min_value * scale_factor),
+        })
+    }
+
+    /// Makes sure the quantile range, if one is set, is a valid range.
+    fn check_quantile_range(&self) -> MLResult<()> {
+        if let Some((lower, upper)) = self.quantile_range {
+            if !(0.0..=1.0).contains(&lower) || !(0.0..=1.0).contains(&upper) || lower >= upper {
+                return Err(Error::new(
+                    ErrorKind::InvalidParameters,
+                    format!(
+                        "Quantile range must satisfy 0 <= lower < upper <= 1, got ({}, {})",
+                        lower, upper
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Stores the feature minimums and maximums and computes the scale and
+    /// constant factors from them. Columns that are not scaled get factors
+    /// that leave their values unchanged.
+    fn set_factors(&mut self, min_values: Vec<f64>, max_values: Vec<f64>) {
+        let scaled_difference = self.scaled_max - self.scaled_min;
+        self.scale_factors = min_values
+            .iter()
+            .zip(max_values.iter())
+            .enumerate()
+            .map(|(idx, (min, max))| {
+                if self.is_scaled(idx) {
+                    scaled_difference / (max - min)
+                } else {
+                    1.0
+                }
+            })
+            .collect();
+        self.constant_factors = min_values
+            .iter()
+            .zip(self.scale_factors.iter())
+            .enumerate()
+            .map(|(idx, (min, scale_factor))| {
+                if self.is_scaled(idx) {
+                    self.scaled_min - (min * scale_factor)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        self.min_values = min_values;
+        self.max_values = max_values;
+    }
+
+    /// Checks whether the feature at the given index is scaled.
+    fn is_scaled(&self, idx: usize) -> bool {
+        match &self.scaled_columns {
+            Some(columns) => columns.contains(&self.columns[idx]),
+            None => true,
+        }
+    }
+
+    /// Returns the scaled columns, if only a subset of the columns is scaled.
+    pub fn scaled_columns(&self) -> Option<&Vec<String>> {
+        self.scaled_columns.as_ref()
+    }
+
     /// Returns the number of features in the dataset.
     pub fn num_features(&self) -> &usize {
         &self.num_featues
     }
 
+    /// Returns a reference to the feature column names seen at fit time.
+    pub fn columns(&self) -> &Vector<String> {
+        &self.columns
+    }
+
     /// Returns a tuple of references to the scaled_min and scaled_max.
     pub fn min_max(&self) -> (&f64, &f64) {
         (&self.scaled_min, &self.scaled_max)
@@ -175,8 +657,11 @@ impl<Y> Default for MinMaxFitter<Y> {
     fn default() -> Self {
         MinMaxFitter {
             num_featues: usize::default(),
+            columns: Vector::new(Vec::new()),
             scaled_min: 0.0,
             scaled_max: 1.0,
+            quantile_range: None,
+            scaled_columns: None,
             min_values: Vec::default(),
             max_values: Vec::default(),
             scale_factors: Vec::default(),
@@ -187,6 +672,31 @@ impl<Y> Default for MinMaxFitter<Y> {
     }
 }
 
+impl<Y> MinMaxFitter<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Fits the scaler on only the named columns. The other columns pass
+    /// through untouched, both here and when transforming.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    /// - columns: The names of the columns to scale.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped MinMaxScaler, or an InvalidParameters error if a
+    ///   column name is not in the dataset.
+    ///
+    pub fn fit_columns(
+        mut self,
+        input: &Dataset<Matrix<f64>, Vector<Y>>,
+        columns: &[&str],
+    ) -> MLResult<MinMaxScaler<Y>> {
+        self.scaled_columns = Some(columns.iter().map(|c| c.to_string()).collect());
+        self.fit(input)
+    }
+}
+
 impl<Y> PreprocessorFitter<Dataset<Matrix<f64>, Vector<Y>>, MinMaxScaler<Y>> for MinMaxFitter<Y>
 where
     Y: Clone + Debug,
@@ -197,15 +707,25 @@ where
     /// - input: Reference to the Dataset to fit on.
     ///
     /// #### Returns:
-    /// - MLResult wrapped MinMaxScaler.
+    /// - MLResult wrapped MinMaxScaler, or an InvalidData error if a quantile
+    ///   range is set and the dataset is empty.
     ///
     fn fit(mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<MinMaxScaler<Y>> {
+        self.check_quantile_range()?;
+        if self.quantile_range.is_some() && input.data().rows() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot fit a quantile range on an empty dataset",
+            ));
+        }
+        if let Some(scaled_columns) = &self.scaled_columns {
+            check_scaled_columns(scaled_columns, input.data_columns().data())?;
+        }
         let num_features = input.data_columns().size();
         self.num_featues = num_features;
+        self.columns = input.data_columns().clone();
         let mut min_values = vec![f64::MAX; num_features];
         let mut max_values = vec![f64::MIN; num_features];
-        let mut scale_factors = vec![0.0; num_features];
-        let mut constant_factors = vec![0.0; num_features];
 
         for row in input.data().row_iter() {
             for (idx, &value) in row.iter().enumerate() {
@@ -217,21 +737,16 @@ where
                 }
             }
         }
-
-        self.fit = FitStatus::Fit;
-        self.min_values = min_values.clone();
-        self.max_values = max_values.clone();
-
-        for i in 0..num_features {
-            let scaled_difference = self.scaled_max - self.scaled_min;
-            let scale_factor = (scaled_difference) / (max_values[i] - min_values[i]);
-            scale_factors[i] = scale_factor;
-            let constant_factor = self.scaled_min - (min_values[i] * scale_factor);
-            constant_factors[i] = constant_factor;
+        if let Some((lower, upper)) = self.quantile_range {
+            for idx in 0..num_features {
+                let sorted = sorted_values(input.data().col(idx).iter());
+                min_values[idx] = sorted_quantile(&sorted, lower);
+                max_values[idx] = sorted_quantile(&sorted, upper);
+            }
         }
 
-        self.scale_factors = scale_factors.clone();
-        self.constant_factors = constant_factors.clone();
+        self.fit = FitStatus::Fit;
+        self.set_factors(min_values, max_values);
 
         Ok(MinMaxScaler { fitter: self })
     }
@@ -241,3 +756,23 @@ where
         &self.fit
     }
 }
+
+/// Helper function that makes sure every column to scale is one of the
+/// feature columns.
+///
+/// #### Parameters:
+/// - scaled_columns: The names of the columns to scale.
+/// - columns: The feature column names.
+///
+/// #### Returns:
+/// - Empty MLResult or an InvalidParameters error naming the unknown column.
+///
+fn check_scaled_columns(scaled_columns: &[String], columns: &[String]) -> MLResult<()> {
+    match scaled_columns.iter().find(|c| !columns.contains(c)) {
+        Some(unknown) => Err(Error::new(
+            ErrorKind::InvalidParameters,
+            format!("Column {} not found in dataset", unknown),
+        )),
+        None => Ok(()),
+    }
+}
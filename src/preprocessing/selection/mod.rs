@@ -0,0 +1,9 @@
+//! # Selection Module
+//!
+//! The module for the feature selectors.
+//!
+//! ## Features
+//! - Select K Best
+
+/// Module for selecting the features most correlated with the target.
+pub mod select_k_best;
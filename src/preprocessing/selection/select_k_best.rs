@@ -0,0 +1,211 @@
+//! # Select K Best
+//!
+//! This module defines a feature selector that scores each feature by the
+//! absolute Pearson correlation with a numeric target and keeps the `k`
+//! highest scoring features.
+//!
+//! ## Examples
+//! ```
+//! use rust_ml::dataset::synthetic::make_regression;
+//! use rust_ml::linalg::BaseMatrix;
+//! use rust_ml::preprocessing::selection::select_k_best::SelectKBestFitter;
+//! use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+//!
+//! let (dataset, _) = make_regression(100, 5, 0.1, 42);
+//!
+//! let selector_fitter = SelectKBestFitter::new(2);
+//! let mut selector = selector_fitter.fit(&dataset).unwrap();
+//! let reduced_dataset = selector.transform(&dataset).unwrap();
+//!
+//! assert_eq!(selector.fitter().fit_status(), &FitStatus::Fit);
+//! assert_eq!(reduced_dataset.data().cols(), 2);
+//! ```
+
+use crate::base::error::{Error, ErrorKind};
+use crate::base::MLResult;
+use crate::dataset::Dataset;
+use crate::linalg::{BaseMatrix, Matrix, Vector};
+use crate::preprocessing::{check_columns, FitStatus, Preprocessor, PreprocessorFitter};
+use std::fmt::Debug;
+
+/// Struct for the Select K Best feature selector.
+#[derive(Clone, Debug)]
+pub struct SelectKBest<Y> {
+    /// The fitter.
+    fitter: SelectKBestFitter<Y>,
+}
+
+impl<Y> SelectKBest<Y> {
+    /// Returns a reference to the fitter.
+    pub fn fitter(&self) -> &SelectKBestFitter<Y> {
+        &self.fitter
+    }
+}
+
+impl<Y> Preprocessor<Dataset<Matrix<f64>, Vector<Y>>> for SelectKBest<Y>
+where
+    Y: Clone + Debug,
+{
+    type O = Dataset<Matrix<f64>, Vector<Y>>;
+
+    /// Keeps only the selected features and returns a new Dataset struct.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to reduce.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Dataset with the selected features, in their
+    ///   original order.
+    ///
+    fn transform(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<Self::O> {
+        check_columns(&self.fitter.columns, input.data_columns())?;
+
+        Ok(Dataset::new(
+            input.data().select_cols(&self.fitter.selected),
+            input.target().clone(),
+            self.fitter.selected_columns.clone(),
+            input.target_column().to_string(),
+        ))
+    }
+}
+
+/// Struct for the fitter for the Select K Best feature selector.
+#[derive(Clone, Debug)]
+pub struct SelectKBestFitter<Y> {
+    /// The number of features to keep.
+    k: usize,
+    /// The feature column names seen at fit time.
+    columns: Vector<String>,
+    /// The absolute correlation of each feature with the target.
+    scores: Vec<f64>,
+    /// The indices of the selected features, in their original order.
+    selected: Vec<usize>,
+    /// The names of the selected features, in their original order.
+    selected_columns: Vector<String>,
+    /// Indicates whether the fitter has been fit.
+    fit: FitStatus,
+    phantom: std::marker::PhantomData<Y>,
+}
+
+impl<Y> SelectKBestFitter<Y> {
+    /// Create a new instance of the SelectKBestFitter.
+    ///
+    /// #### Parameters
+    /// - k: The number of features to keep.
+    ///
+    pub fn new(k: usize) -> Self {
+        SelectKBestFitter {
+            k,
+            columns: Vector::new(Vec::new()),
+            scores: Vec::new(),
+            selected: Vec::new(),
+            selected_columns: Vector::new(Vec::new()),
+            fit: FitStatus::NotFit,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the number of features to keep.
+    pub fn k(&self) -> &usize {
+        &self.k
+    }
+
+    /// Returns a reference to the absolute target correlation of each feature.
+    pub fn scores(&self) -> &Vec<f64> {
+        &self.scores
+    }
+
+    /// Returns a reference to the names of the selected features.
+    pub fn selected_columns(&self) -> &Vector<String> {
+        &self.selected_columns
+    }
+}
+
+impl<Y> PreprocessorFitter<Dataset<Matrix<f64>, Vector<Y>>, SelectKBest<Y>> for SelectKBestFitter<Y>
+where
+    Y: Clone + Debug + Into<f64>,
+{
+    /// Scores the features against the target and selects the `k` best.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped SelectKBest.
+    ///
+    fn fit(mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<SelectKBest<Y>> {
+        let num_features = input.data().cols();
+        if self.k == 0 || self.k > num_features {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!(
+                    "Number of features to keep ({}) must be between 1 and the number of features ({})",
+                    self.k, num_features
+                ),
+            ));
+        }
+        if input.data().rows() < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "At least two rows are needed to score features",
+            ));
+        }
+
+        let target: Vec<f64> = input.target().iter().map(|y| y.clone().into()).collect();
+        let scores: Vec<f64> = (0..num_features)
+            .map(|idx| {
+                let feature: Vec<f64> = input.data().col(idx).iter().cloned().collect();
+                abs_correlation(&feature, &target)
+            })
+            .collect();
+
+        // Stable sort keeps the column order between equally scored features.
+        let mut order: Vec<usize> = (0..num_features).collect();
+        order.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+        let mut selected = order[..self.k].to_vec();
+        selected.sort();
+
+        self.selected_columns = Vector::new(
+            selected
+                .iter()
+                .map(|&idx| input.data_columns()[idx].clone())
+                .collect::<Vec<String>>(),
+        );
+        self.columns = input.data_columns().clone();
+        self.scores = scores;
+        self.selected = selected;
+        self.fit = FitStatus::Fit;
+        Ok(SelectKBest { fitter: self })
+    }
+
+    /// Get the fit status for the preprocessor fitter.
+    fn fit_status(&self) -> &FitStatus {
+        &self.fit
+    }
+}
+
+/// Helper function that computes the absolute Pearson correlation of two
+/// equally long slices. A constant slice has no correlation and scores 0.
+///
+/// #### Parameters:
+/// - x: The first values.
+/// - y: The second values.
+///
+/// #### Returns:
+/// - The absolute correlation in the range [0, 1].
+///
+fn abs_correlation(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len() as f64;
+    let x_mean = x.iter().sum::<f64>() / n;
+    let y_mean = y.iter().sum::<f64>() / n;
+    let (mut covariance, mut x_variance, mut y_variance) = (0.0, 0.0, 0.0);
+    for (a, b) in x.iter().zip(y.iter()) {
+        covariance += (a - x_mean) * (b - y_mean);
+        x_variance += (a - x_mean).powi(2);
+        y_variance += (b - y_mean).powi(2);
+    }
+    if x_variance == 0.0 || y_variance == 0.0 {
+        return 0.0;
+    }
+    (covariance / (x_variance * y_variance).sqrt()).abs()
+}
@@ -0,0 +1,249 @@
+//! # Winsorize Module
+//!
+//! This module defines the winsorizer, which caps extreme values without
+//! dropping rows. The fitter finds the lower and upper percentile values of
+//! each feature and the winsorizer clamps values outside of those bounds.
+//!
+//! ## Examples
+//! ```
+//! use rust_ml::dataset::iris;
+//! use rust_ml::preprocessing::winsorize::WinsorizerFitter;
+//! use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+//!
+//! let iris_dataset = iris::load();
+//!
+//! let winsorizer_fitter = WinsorizerFitter::default();
+//! let mut winsorizer = winsorizer_fitter.fit(&iris_dataset).unwrap();
+//! let winsorized_dataset = winsorizer.transform(&iris_dataset).unwrap();
+//!
+//! assert_eq!(winsorizer.fitter().fit_status(), &FitStatus::Fit);
+//! assert_eq!(winsorizer.fitter().upper_bounds().len(), 5);
+//! ```
+
+use crate::base::error::{Error, ErrorKind};
+use crate::base::stats::{sorted_quantile, sorted_values};
+use crate::base::MLResult;
+use crate::dataset::Dataset;
+use crate::linalg::{BaseMatrix, Matrix, Vector};
+use crate::preprocessing::{
+    check_fit, DatasetTransform, FitStatus, Preprocessor, PreprocessorFitter,
+};
+use std::fmt::Debug;
+
+/// Struct for the Winsorizer.
+#[derive(Clone, Debug)]
+pub struct Winsorizer<Y> {
+    /// The fitter.
+    fitter: WinsorizerFitter<Y>,
+}
+
+impl<Y> Winsorizer<Y> {
+    /// Creates a winsorizer that has not been fit yet from the fitter's
+    /// percentiles, to be fit later through `DatasetTransform`. Any bounds the
+    /// fitter was already fit with are discarded.
+    ///
+    /// #### Parameters:
+    /// - fitter: The fitter holding the lower and upper percentiles.
+    ///
+    pub fn unfit_from(fitter: WinsorizerFitter<Y>) -> Self {
+        Winsorizer {
+            fitter: WinsorizerFitter::new(fitter.lower_percentile, fitter.upper_percentile),
+        }
+    }
+
+    /// Returns a reference to the fitter.
+    pub fn fitter(&self) -> &WinsorizerFitter<Y> {
+        &self.fitter
+    }
+}
+
+impl<Y> Preprocessor<Dataset<Matrix<f64>, Vector<Y>>> for Winsorizer<Y>
+where
+    Y: Clone + Debug,
+{
+    type O = Dataset<Matrix<f64>, Vector<Y>>;
+
+    /// Clamps each feature value into the fitted bounds and returns a new
+    /// Dataset struct.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to winsorize.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped winsorized Dataset.
+    ///
+    fn transform(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<Self::O> {
+        DatasetTransform::transform(self, input)
+    }
+}
+
+impl<Y> DatasetTransform<Y> for Winsorizer<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Refits the percentile bounds on a given dataset, keeping the percentiles.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - Empty MLResult.
+    ///
+    fn fit(&mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<()> {
+        let (&lower, &upper) = self.fitter.percentiles();
+        *self = WinsorizerFitter::new(lower, upper).fit(input)?;
+        Ok(())
+    }
+
+    /// Clamps each feature value into the fitted bounds and returns a new
+    /// Dataset struct.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to winsorize.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped winsorized Dataset.
+    ///
+    fn transform(
+        &self,
+        input: &Dataset<Matrix<f64>, Vector<Y>>,
+    ) -> MLResult<Dataset<Matrix<f64>, Vector<Y>>> {
+        check_fit(&self.fitter.fit)?;
+        let num_features = self.fitter.lower_bounds.len();
+        if num_features != input.data_columns().size() {
+            return Err(Error::new(
+                ErrorKind::InvalidState,
+                format!(
+                    "Fitter's number of features ({}) does not match dataset's number of features ({})",
+                    num_features,
+                    input.data_columns().size()
+                ),
+            ));
+        }
+        let mut clamped_data = Vec::with_capacity(input.data().data().len());
+
+        for row in input.data().row_iter() {
+            for (idx, &value) in row.iter().enumerate() {
+                clamped_data.push(
+                    value.clamp(self.fitter.lower_bounds[idx], self.fitter.upper_bounds[idx]),
+                );
+            }
+        }
+
+        Ok(Dataset::new(
+            Matrix::new(input.data().rows(), num_features, clamped_data),
+            input.target().clone(),
+            input.data_columns().clone(),
+            input.target_column().to_string(),
+        ))
+    }
+}
+
+/// Struct for the fitter for the Winsorizer.
+#[derive(Clone, Debug)]
+pub struct WinsorizerFitter<Y> {
+    /// The lower percentile, as a fraction in [0, 1].
+    lower_percentile: f64,
+    /// The upper percentile, as a fraction in [0, 1].
+    upper_percentile: f64,
+    /// The lower bound value for each feature.
+    lower_bounds: Vec<f64>,
+    /// The upper bound value for each feature.
+    upper_bounds: Vec<f64>,
+    /// Indicates whether the fitter has been fit.
+    fit: FitStatus,
+    phantom: std::marker::PhantomData<Y>,
+}
+
+impl<Y> WinsorizerFitter<Y> {
+    /// Create a new instance of the WinsorizerFitter with custom percentiles.
+    ///
+    /// #### Parameters
+    /// - lower: The lower percentile as a fraction (e.g. 0.01 for the 1st percentile).
+    /// - upper: The upper percentile as a fraction (e.g. 0.99 for the 99th percentile).
+    ///
+    pub fn new(lower: f64, upper: f64) -> Self {
+        WinsorizerFitter {
+            lower_percentile: lower,
+            upper_percentile: upper,
+            lower_bounds: Vec::new(),
+            upper_bounds: Vec::new(),
+            fit: FitStatus::NotFit,
+            phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns a tuple of the lower and upper percentiles.
+    pub fn percentiles(&self) -> (&f64, &f64) {
+        (&self.lower_percentile, &self.upper_percentile)
+    }
+
+    /// Returns a reference to the lower bound of each feature.
+    pub fn lower_bounds(&self) -> &Vec<f64> {
+        &self.lower_bounds
+    }
+
+    /// Returns a reference to the upper bound of each feature.
+    pub fn upper_bounds(&self) -> &Vec<f64> {
+        &self.upper_bounds
+    }
+}
+
+impl<Y> Default for WinsorizerFitter<Y> {
+    /// Creates a WinsorizerFitter that caps values at the 1st and 99th percentiles.
+    fn default() -> Self {
+        WinsorizerFitter::new(0.01, 0.99)
+    }
+}
+
+impl<Y> PreprocessorFitter<Dataset<Matrix<f64>, Vector<Y>>, Winsorizer<Y>> for WinsorizerFitter<Y>
+where
+    Y: Clone + Debug,
+{
+    /// Fits the percentile bounds for each feature of the dataset.
+    ///
+    /// #### Parameters:
+    /// - input: Reference to the Dataset to fit on.
+    ///
+    /// #### Returns:
+    /// - MLResult wrapped Winsorizer.
+    ///
+    fn fit(mut self, input: &Dataset<Matrix<f64>, Vector<Y>>) -> MLResult<Winsorizer<Y>> {
+        if !(0.0..=1.0).contains(&self.lower_percentile)
+            || !(0.0..=1.0).contains(&self.upper_percentile)
+            || self.lower_percentile >= self.upper_percentile
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidParameters,
+                format!(
+                    "Percentiles must satisfy 0 <= lower < upper <= 1, got ({}, {})",
+                    self.lower_percentile, self.upper_percentile
+                ),
+            ));
+        }
+        if input.data().rows() == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cannot fit percentile bounds on an empty dataset",
+            ));
+        }
+
+        let mut lower_bounds = Vec::with_capacity(input.data().cols());
+        let mut upper_bounds = Vec::with_capacity(input.data().cols());
+        for idx in 0..input.data().cols() {
+            let sorted = sorted_values(input.data().col(idx).iter());
+            lower_bounds.push(sorted_quantile(&sorted, self.lower_percentile));
+            upper_bounds.push(sorted_quantile(&sorted, self.upper_percentile));
+        }
+
+        self.lower_bounds = lower_bounds;
+        self.upper_bounds = upper_bounds;
+        self.fit = FitStatus::Fit;
+        Ok(Winsorizer { fitter: self })
+    }
+
+    /// Get the fit status for the preprocessor fitter.
+    fn fit_status(&self) -> &FitStatus {
+        &self.fit
+    }
+}
@@ -0,0 +1,60 @@
+use rust_ml::base::error::ErrorKind;
+use rust_ml::dataset::Dataset;
+use rust_ml::linalg::{BaseMatrix, Matrix, Vector};
+use rust_ml::preprocessing::encoders::cyclical::CyclicalEncoderFitter;
+use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+
+#[test]
+fn cyclical_encoder_test() {
+    let months: Vec<f64> = (0..=12).map(|m| m as f64).collect();
+    let dataset = Dataset::new(
+        Matrix::new(13, 1, months),
+        Vector::new(vec![0; 13]),
+        Vector::new(vec!["month".to_string()]),
+        "label".to_string(),
+    );
+
+    let mut encoder = CyclicalEncoderFitter::new("month", 12.0)
+        .fit(&dataset)
+        .unwrap();
+    let encoded_dataset = encoder.transform(&dataset).unwrap();
+
+    assert_eq!(encoder.fitter().fit_status(), &FitStatus::Fit);
+    assert_eq!(
+        encoded_dataset.data_columns(),
+        &Vector::new(vec!["month_sin".to_string(), "month_cos".to_string()])
+    );
+    let data = encoded_dataset.data();
+    assert_eq!(data.cols(), 2);
+    // Month 0 and month 12 land on the same point of the cycle.
+    assert!((data[[0, 0]] - data[[12, 0]]).abs() < 1e-12);
+    assert!((data[[0, 1]] - data[[12, 1]]).abs() < 1e-12);
+    assert_eq!(data[[0, 1]], 1.0);
+    assert!((data[[3, 0]] - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn cyclical_encoder_invalid_test() {
+    let dataset = Dataset::new(
+        Matrix::new(2, 2, vec![1.0, 10.0, 2.0, 20.0]),
+        Vector::new(vec![0, 1]),
+        Vector::new(vec!["hour".to_string(), "amount".to_string()]),
+        "label".to_string(),
+    );
+
+    let error = CyclicalEncoderFitter::new("hour", 0.0)
+        .fit(&dataset)
+        .unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::InvalidParameters));
+    assert!(CyclicalEncoderFitter::new("minute", 60.0)
+        .fit(&dataset)
+        .is_err());
+
+    let encoded_dataset = CyclicalEncoderFitter::new("hour", 24.0)
+        .fit(&dataset)
+        .unwrap()
+        .transform(&dataset)
+        .unwrap();
+    assert_eq!(encoded_dataset.data().cols(), 3);
+    assert_eq!(encoded_dataset.data()[[1, 2]], 20.0);
+}
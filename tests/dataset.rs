@@ -0,0 +1,774 @@
+use rust_ml::base::error::ErrorKind;
+use rust_ml::dataset::{iris, Column, Dataset, DatasetBuilder, MixedDataValue, MixedDataset};
+use rust_ml::linalg::{Axes, BaseMatrix, Matrix, Vector};
+
+#[test]
+fn balanced_sample_test() {
+    let iris_dataset = iris::load();
+    let sample = iris_dataset.balanced_sample(10, 42).unwrap();
+
+    assert_eq!(30, sample.data().rows());
+    assert_eq!(30, sample.target().size());
+    assert_eq!(iris_dataset.data_columns(), sample.data_columns());
+    for species in ["Iris-setosa", "Iris-versicolor", "Iris-virginica"] {
+        assert_eq!(10, sample.target().iter().filter(|s| *s == species).count());
+    }
+    assert!(iris_dataset.balanced_sample(51, 42).is_err());
+}
+
+#[test]
+fn duplicate_rows_test() {
+    let dataset = Dataset::new(
+        Matrix::new(4, 2, vec![1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 5.0, 6.0]),
+        Vector::new(vec![0.0, 1.0, 0.0, 1.0]),
+        Vector::new(vec!["a".to_string(), "b".to_string()]),
+        "label".to_string(),
+    );
+
+    assert_eq!(dataset.duplicate_row_indices(), vec![vec![0, 2]]);
+    let deduplicated = dataset.drop_duplicate_rows();
+    assert_eq!(3, deduplicated.data().rows());
+    assert_eq!(&Vector::new(vec![0.0, 1.0, 1.0]), deduplicated.target());
+    assert!(deduplicated.duplicate_row_indices().is_empty());
+}
+
+#[test]
+fn reorder_columns_test() {
+    let iris_dataset = iris::load();
+    let order = [
+        "PetalWidthCm",
+        "Id",
+        "SepalWidthCm",
+        "PetalLengthCm",
+        "SepalLengthCm",
+    ];
+    let reordered = iris_dataset.reorder_columns(&order).unwrap();
+
+    assert_eq!(
+        &Vector::new(order.iter().map(|s| s.to_string()).collect::<Vec<String>>()),
+        reordered.data_columns()
+    );
+    assert_eq!(iris_dataset.data()[[10, 4]], reordered.data()[[10, 0]]);
+    assert_eq!(iris_dataset.data()[[10, 1]], reordered.data()[[10, 4]]);
+    assert!(iris_dataset.reorder_columns(&order[1..]).is_err());
+    assert!(iris_dataset
+        .reorder_columns(&["Id", "Id", "SepalWidthCm", "PetalLengthCm", "SepalLengthCm"])
+        .is_err());
+    assert!(iris_dataset
+        .reorder_columns(&[
+            "Foo",
+            "Id",
+            "SepalWidthCm",
+            "PetalLengthCm",
+            "SepalLengthCm"
+        ])
+        .is_err());
+}
+
+#[test]
+fn group_means_test() {
+    let iris_dataset = iris::load();
+    let means = iris_dataset.group_means().unwrap();
+
+    assert_eq!(3, means.len());
+    let setosa_means = &means["Iris-setosa"];
+    assert_eq!(5, setosa_means.size());
+    assert!((setosa_means[3] - 1.464).abs() < 1e-9);
+    assert!((setosa_means[1] - 5.006).abs() < 1e-9);
+}
+
+#[test]
+fn encode_target_test() {
+    let iris_dataset = iris::load();
+    let features = iris_dataset.data().clone();
+    let (encoded_dataset, label_map) = iris_dataset.encode_target().unwrap();
+
+    assert_eq!(3, label_map.len());
+    assert_eq!(label_map["Iris-setosa"], 0.0);
+    assert_eq!(150, encoded_dataset.target().size());
+    assert_eq!(encoded_dataset.target()[0], 0.0);
+    assert_eq!(encoded_dataset.target()[149], 2.0);
+    assert_eq!(&features, encoded_dataset.data());
+}
+
+#[test]
+fn skewness_kurtosis_test() {
+    // The first column has a long right tail, the second is constant.
+    let dataset = Dataset::new(
+        Matrix::new(
+            6,
+            2,
+            vec![1.0, 3.0, 1.0, 3.0, 2.0, 3.0, 2.0, 3.0, 3.0, 3.0, 12.0, 3.0],
+        ),
+        Vector::new(vec![0.0; 6]),
+        Vector::new(vec!["skewed".to_string(), "constant".to_string()]),
+        "label".to_string(),
+    );
+
+    let skewness = dataset.skewness();
+    assert!(skewness[0] > 1.0);
+    assert_eq!(skewness[1], 0.0);
+
+    let kurtosis = dataset.kurtosis();
+    assert!(kurtosis[0] > 3.0);
+    assert_eq!(kurtosis[1], 0.0);
+
+    // A constant column whose variance does not round to exactly zero.
+    let dataset = Dataset::new(
+        Matrix::new(7, 1, vec![0.1; 7]),
+        Vector::new(vec![0.0; 7]),
+        Vector::new(vec!["constant".to_string()]),
+        "label".to_string(),
+    );
+    assert_eq!(dataset.skewness(), vec![0.0]);
+    assert_eq!(dataset.kurtosis(), vec![0.0]);
+}
+
+#[test]
+fn skip_missing_stats_test() {
+    let dataset: MixedDataset<Vector<String>> = MixedDataset::from_csv_with_na(
+        "tests/data/missing.csv",
+        "label",
+        &["height", "weight"],
+        &[""],
+    )
+    .unwrap();
+
+    assert_eq!(&MixedDataValue::Missing, dataset.get(1, "height").unwrap());
+    let mean = dataset.column_mean_skip_missing("height").unwrap();
+    assert!((mean - (1.5 + 1.8 + 2.1 + 1.2) / 4.0).abs() < 1e-12);
+    assert_eq!(dataset.column_min_skip_missing("weight").unwrap(), 55.5);
+    assert_eq!(dataset.column_max_skip_missing("weight").unwrap(), 81.0);
+    let std = dataset.column_std_skip_missing("height").unwrap();
+    assert!((std - 0.1125_f64.sqrt()).abs() < 1e-12);
+
+    assert!(dataset.column_mean_skip_missing("color").is_err());
+    assert!(dataset.column_mean_skip_missing("depth").is_err());
+
+    let all_missing = MixedDataset::new(
+        vec![vec![MixedDataValue::Missing], vec![MixedDataValue::Missing]],
+        Vector::new(vec![0.0, 1.0]),
+        Vector::new(vec!["empty".to_string()]),
+        "label".to_string(),
+    );
+    assert!(all_missing.column_mean_skip_missing("empty").is_err());
+
+    // The strict loader rejects empty numeric cells.
+    let err = MixedDataset::<Vector<String>>::from_csv(
+        "tests/data/missing.csv",
+        "label",
+        &["height", "weight"],
+    )
+    .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidData));
+}
+
+#[test]
+fn from_csv_with_na_test() {
+    let dataset: MixedDataset<Vector<String>> = MixedDataset::from_csv_with_na(
+        "tests/data/na_values.csv",
+        "label",
+        &["height", "weight"],
+        &["?", "NA"],
+    )
+    .unwrap();
+
+    assert_eq!(&MixedDataValue::Missing, dataset.get(1, "height").unwrap());
+    assert_eq!(&MixedDataValue::Missing, dataset.get(2, "color").unwrap());
+    assert_eq!(&MixedDataValue::Missing, dataset.get(2, "weight").unwrap());
+    assert_eq!(
+        &MixedDataValue::Numeric(1.8),
+        dataset.get(2, "height").unwrap()
+    );
+    assert_eq!(
+        &MixedDataValue::Categorical("blue".to_string()),
+        dataset.get(1, "color").unwrap()
+    );
+
+    // Without the markers the "?" in a numeric column fails to parse.
+    assert!(MixedDataset::<Vector<String>>::from_csv(
+        "tests/data/na_values.csv",
+        "label",
+        &["height", "weight"]
+    )
+    .is_err());
+}
+
+#[test]
+fn constant_feature_names_test() {
+    let iris_dataset = iris::load();
+    let num_rows = iris_dataset.data().rows();
+    let mut values = Vec::with_capacity(num_rows * 7);
+    for (idx, row) in iris_dataset.data().row_iter().enumerate() {
+        values.extend(row.iter());
+        values.push(3.0);
+        values.push(1.0 + (idx % 2) as f64 * 1e-9);
+    }
+    let mut columns = iris_dataset.data_columns().clone().into_vec();
+    columns.push("Constant".to_string());
+    columns.push("NearConstant".to_string());
+    let dataset = Dataset::new(
+        Matrix::new(num_rows, 7, values),
+        iris_dataset.target().clone(),
+        Vector::new(columns),
+        iris_dataset.target_column().to_string(),
+    );
+
+    assert_eq!(
+        vec!["Constant".to_string()],
+        dataset.constant_feature_names(0.0)
+    );
+    assert_eq!(
+        vec!["Constant".to_string(), "NearConstant".to_string()],
+        dataset.constant_feature_names(1e-6)
+    );
+    assert!(iris_dataset.constant_feature_names(1e-6).is_empty());
+}
+
+#[test]
+fn mixed_from_columns_test() {
+    let dataset = MixedDataset::from_columns(
+        vec![
+            ("height".to_string(), Column::Numeric(vec![1.5, 1.8, 2.1])),
+            (
+                "color".to_string(),
+                Column::Categorical(vec![
+                    "red".to_string(),
+                    "blue".to_string(),
+                    "red".to_string(),
+                ]),
+            ),
+            (
+                "weight".to_string(),
+                Column::Numeric(vec![60.0, 72.5, 81.0]),
+            ),
+        ],
+        Vector::new(vec![0, 1, 0]),
+        "label".to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(3, dataset.data().len());
+    assert_eq!(
+        &vec![
+            MixedDataValue::Numeric(1.8),
+            MixedDataValue::Categorical("blue".to_string()),
+            MixedDataValue::Numeric(72.5),
+        ],
+        &dataset.data()[1]
+    );
+    assert_eq!(
+        &Vector::new(vec![
+            "height".to_string(),
+            "color".to_string(),
+            "weight".to_string()
+        ]),
+        dataset.data_columns()
+    );
+
+    let mismatched = MixedDataset::from_columns(
+        vec![("height".to_string(), Column::Numeric(vec![1.5, 1.8]))],
+        Vector::new(vec![0, 1, 0]),
+        "label".to_string(),
+    );
+    assert!(mismatched.is_err());
+}
+
+#[test]
+fn from_csv_encoded_test() {
+    let (dataset, label_map) =
+        Dataset::from_csv_encoded("./src/dataset/data/iris.csv", "Species").unwrap();
+
+    let mut codes: Vec<f64> = dataset.target().iter().cloned().collect();
+    codes.sort_by(|a, b| a.total_cmp(b));
+    codes.dedup();
+    assert_eq!(vec![0.0, 1.0, 2.0], codes);
+    assert_eq!(3, label_map.len());
+    assert_eq!(Some(&0.0), label_map.get("Iris-setosa"));
+    assert_eq!(150, dataset.data().rows());
+}
+
+#[test]
+fn class_centroids_test() {
+    let iris_dataset = iris::load();
+    let (classes, centroids) = iris_dataset.class_centroids().unwrap();
+
+    assert_eq!(
+        vec![
+            "Iris-setosa".to_string(),
+            "Iris-versicolor".to_string(),
+            "Iris-virginica".to_string()
+        ],
+        classes
+    );
+    assert_eq!(3, centroids.rows());
+    assert_eq!(5, centroids.cols());
+    // Petal length is the fourth feature.
+    assert!(centroids[[0, 3]] < 2.0);
+    assert!(centroids[[1, 3]] > 3.5);
+    assert!(centroids[[2, 3]] > 3.5);
+}
+
+#[test]
+fn class_covariances_test() {
+    let iris_dataset = iris::load();
+    let (classes, covariances) = iris_dataset.class_covariances().unwrap();
+
+    assert_eq!(classes.len(), 3);
+    assert_eq!(classes[0], "Iris-setosa");
+    assert_eq!(covariances.len(), 3);
+    for covariance in &covariances {
+        assert_eq!(covariance.rows(), 5);
+        assert_eq!(covariance.cols(), 5);
+        for i in 0..5 {
+            assert!(covariance[[i, i]] >= 0.0);
+            for j in 0..5 {
+                assert!((covariance[[i, j]] - covariance[[j, i]]).abs() < 1e-12);
+            }
+        }
+    }
+    // Ids 1 to 50 have a sample variance of 50 * 51 / 12.
+    assert!((covariances[0][[0, 0]] - 212.5).abs() < 1e-9);
+
+    let single_sample = Dataset::new(
+        Matrix::new(3, 1, vec![1.0, 2.0, 3.0]),
+        Vector::new(vec![0, 0, 1]),
+        Vector::new(vec!["x".to_string()]),
+        "label".to_string(),
+    );
+    let error = single_sample.class_covariances().unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::InvalidData));
+}
+
+#[test]
+fn vif_test() {
+    let num_rows = 30;
+    let mut values = Vec::with_capacity(num_rows * 4);
+    for i in 0..num_rows {
+        let x1 = i as f64;
+        let x2 = ((i * i) % 7) as f64;
+        values.extend([x1, x2, 2.0 * x1 - x2 + 1.0, (i as f64).sin()]);
+    }
+    let dataset = Dataset::new(
+        Matrix::new(num_rows, 4, values),
+        Vector::new(vec![0.0; num_rows]),
+        Vector::new(vec![
+            "x1".to_string(),
+            "x2".to_string(),
+            "combined".to_string(),
+            "noise".to_string(),
+        ]),
+        "y".to_string(),
+    );
+
+    let factors = dataset.vif().unwrap();
+    assert_eq!(4, factors.len());
+    assert_eq!("combined", factors[2].0);
+    assert!(factors[2].1 > 1e6);
+    assert_eq!("noise", factors[3].0);
+    assert!(factors[3].1 < 2.0);
+
+    let independent = iris::load().vif().unwrap();
+    assert!(independent.iter().all(|(_, v)| v.is_finite() && *v >= 1.0));
+}
+
+#[test]
+fn target_by_bin_test() {
+    // The target grows with x, and no x falls between 4 and 6.
+    let x = vec![0.0, 1.0, 2.0, 3.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    let y: Vec<f64> = x.iter().map(|v| 2.0 * v + 1.0).collect();
+    let dataset = Dataset::new(
+        Matrix::new(9, 1, x),
+        Vector::new(y),
+        Vector::new(vec!["x".to_string()]),
+        "y".to_string(),
+    );
+
+    let means = dataset.target_by_bin("x", 5).unwrap();
+    assert_eq!(means.len(), 4);
+    assert_eq!(means[0], (1.0, 2.0));
+    assert_eq!(means[1], (3.0, 6.0));
+    assert!(means.windows(2).all(|pair| pair[1].1 > pair[0].1));
+
+    assert!(dataset.target_by_bin("x", 0).is_err());
+    assert!(dataset.target_by_bin("z", 3).is_err());
+}
+
+#[test]
+fn leverage_test() {
+    let dataset = Dataset::new(
+        Matrix::new(
+            6,
+            2,
+            vec![1.0, 0.5, 2.0, 1.5, 3.0, 0.0, 4.0, 2.5, 5.0, 1.0, 9.0, 3.0],
+        ),
+        Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+        Vector::new(vec!["a".to_string(), "b".to_string()]),
+        "y".to_string(),
+    );
+    let leverage = dataset.leverage().unwrap();
+
+    // The trace of the hat matrix is the number of parameters (intercept + 2).
+    assert_eq!(leverage.size(), 6);
+    assert!((leverage.sum() - 3.0).abs() < 1e-9);
+    assert!(leverage.iter().all(|&h| (0.0..=1.0 + 1e-12).contains(&h)));
+
+    // With a single feature the leverage is 1/n + (x - mean)² / Σ(x - mean)².
+    let simple = Dataset::new(
+        Matrix::new(5, 1, vec![1.0, 2.0, 3.0, 4.0, 5.0]),
+        Vector::new(vec![0.0; 5]),
+        Vector::new(vec!["x".to_string()]),
+        "y".to_string(),
+    );
+    assert!((simple.leverage().unwrap()[0] - 0.6).abs() < 1e-9);
+
+    let collinear = Dataset::new(
+        Matrix::new(3, 2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]),
+        Vector::new(vec![1.0, 2.0, 3.0]),
+        Vector::new(vec!["a".to_string(), "b".to_string()]),
+        "y".to_string(),
+    );
+    let error = collinear.leverage().unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::LinAlgError));
+}
+
+#[test]
+fn to_full_matrix_test() {
+    let dataset = Dataset::new(
+        Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+        Vector::new(vec![10.0, 20.0, 30.0]),
+        Vector::new(vec!["a".to_string(), "b".to_string()]),
+        "y".to_string(),
+    );
+
+    let full = dataset.to_full_matrix().unwrap();
+    assert_eq!(3, full.rows());
+    assert_eq!(3, full.cols());
+    assert_eq!(
+        vec![10.0, 20.0, 30.0],
+        full.col(2).iter().cloned().collect::<Vec<f64>>()
+    );
+    assert_eq!(&[3.0, 4.0, 20.0], full.row(1).raw_slice());
+}
+
+#[test]
+fn dataset_builder_test() {
+    let iris_dataset = iris::load();
+
+    let dataset = DatasetBuilder::new()
+        .features(iris_dataset.data().clone())
+        .target(iris_dataset.target().clone())
+        .columns(iris_dataset.data_columns().clone().into_vec())
+        .target_name("Species".to_string())
+        .build()
+        .unwrap();
+    assert_eq!(150, dataset.data().rows());
+    assert_eq!(5, dataset.data().cols());
+    assert_eq!(150, dataset.target().size());
+    assert_eq!(iris_dataset.data_columns(), dataset.data_columns());
+    assert_eq!("Species", dataset.target_column());
+
+    let missing_target = DatasetBuilder::<String>::new()
+        .features(iris_dataset.data().clone())
+        .columns(iris_dataset.data_columns().clone().into_vec())
+        .target_name("Species".to_string())
+        .build();
+    assert!(missing_target.is_err());
+
+    let wrong_columns = DatasetBuilder::new()
+        .features(iris_dataset.data().clone())
+        .target(iris_dataset.target().clone())
+        .columns(vec!["Id".to_string()])
+        .target_name("Species".to_string())
+        .build();
+    assert!(wrong_columns.is_err());
+}
+
+#[test]
+fn xy_test() {
+    let iris_dataset = iris::load();
+
+    let (x, y) = iris_dataset.xy();
+    assert_eq!(iris_dataset.data().rows(), x.rows());
+    assert_eq!(iris_dataset.data().cols(), x.cols());
+    assert_eq!(iris_dataset.target(), y);
+
+    let expected_target = iris_dataset.target().clone();
+    let (x, y) = iris_dataset.xy_owned();
+    assert_eq!((150, 5), (x.rows(), x.cols()));
+    assert_eq!(expected_target, y);
+}
+
+#[test]
+fn histogram_test() {
+    let iris_dataset = iris::load();
+
+    let (edges, counts) = iris_dataset.histogram("PetalLengthCm", 5).unwrap();
+    assert_eq!(6, edges.len());
+    assert_eq!(5, counts.len());
+    assert_eq!(1.0, edges[0]);
+    assert!((edges[5] - 6.9).abs() < 1e-12);
+    assert_eq!(150, counts.iter().sum::<usize>());
+
+    let constant = Dataset::new(
+        Matrix::new(4, 1, vec![2.0; 4]),
+        Vector::new(vec![0.0; 4]),
+        Vector::new(vec!["c".to_string()]),
+        "y".to_string(),
+    );
+    let (_, counts) = constant.histogram("c", 5).unwrap();
+    assert_eq!(1, counts.iter().filter(|&&c| c > 0).count());
+    assert_eq!(4, counts.iter().sum::<usize>());
+
+    assert!(iris_dataset.histogram("PetalLengthCm", 0).is_err());
+    assert!(iris_dataset.histogram("Petal", 5).is_err());
+}
+
+#[test]
+fn validate_test() {
+    let iris_dataset = iris::load();
+    assert!(iris_dataset.validate().is_ok());
+
+    let short_target = Dataset::new(
+        iris_dataset.data().clone(),
+        Vector::new(vec!["Iris-setosa".to_string(); 10]),
+        iris_dataset.data_columns().clone(),
+        "Species".to_string(),
+    );
+    let err = short_target.validate().unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidState));
+
+    let missing_column = Dataset::new(
+        iris_dataset.data().clone(),
+        iris_dataset.target().clone(),
+        Vector::new(vec!["Id".to_string()]),
+        "Species".to_string(),
+    );
+    let err = missing_column.validate().unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidState));
+}
+
+#[test]
+fn filter_by_feature_test() {
+    let iris_dataset = iris::load();
+    let sepal_lengths = iris_dataset.data().col(1);
+    let expected = sepal_lengths.iter().filter(|&&v| v > 6.0).count();
+
+    let filtered = iris_dataset.filter_by_feature(|row| row[1] > 6.0);
+    assert_eq!(filtered.data().rows(), expected);
+    assert_eq!(filtered.target().size(), expected);
+    assert!(filtered.data().col(1).iter().all(|&v| v > 6.0));
+    assert_eq!(filtered.data_columns(), iris_dataset.data_columns());
+
+    assert_eq!(iris_dataset.filter_by_feature(|_| false).data().rows(), 0);
+}
+
+#[test]
+fn epoch_batches_test() {
+    let iris_dataset = iris::load();
+
+    let first_epoch: Vec<_> = iris_dataset.epoch_batches(32, 1).collect();
+    let second_epoch: Vec<_> = iris_dataset.epoch_batches(32, 2).collect();
+    assert_eq!(first_epoch.len(), 5);
+    assert_eq!(first_epoch[4].data().rows(), 22);
+    assert_ne!(first_epoch[0].data(), second_epoch[0].data());
+
+    // Every sample shows up exactly once per epoch; the Id column is unique.
+    let mut ids: Vec<f64> = first_epoch
+        .iter()
+        .flat_map(|batch| batch.data().col(0).iter().cloned().collect::<Vec<f64>>())
+        .collect();
+    ids.sort_by(f64::total_cmp);
+    assert_eq!(ids, (1..=150).map(|id| id as f64).collect::<Vec<f64>>());
+    assert_eq!(
+        first_epoch.iter().map(|b| b.target().size()).sum::<usize>(),
+        150
+    );
+}
+
+#[test]
+fn leakage_candidates_test() {
+    // "code" is the class itself, "score" separates the classes except for
+    // the shared value 2.0 and "noise" does not separate them at all.
+    let dataset = Dataset::new(
+        Matrix::new(
+            6,
+            3,
+            vec![
+                0.0, 1.0, 5.0, //
+                0.0, 2.0, 6.0, //
+                1.0, 2.0, 5.0, //
+                1.0, 3.0, 6.0, //
+                2.0, 4.0, 5.0, //
+                2.0, 4.5, 6.0,
+            ],
+        ),
+        Vector::new(vec!["a", "a", "b", "b", "c", "c"]),
+        Vector::new(vec![
+            "code".to_string(),
+            "score".to_string(),
+            "noise".to_string(),
+        ]),
+        "label".to_string(),
+    );
+    assert_eq!(dataset.leakage_candidates(), vec!["code".to_string()]);
+
+    // The unique Id column of iris identifies every row, and so its class.
+    assert_eq!(iris::load().leakage_candidates(), vec!["Id".to_string()]);
+}
+
+#[test]
+fn non_finite_test() {
+    let iris_dataset = iris::load();
+    assert!(!iris_dataset.has_non_finite());
+    assert!(iris_dataset.non_finite_locations().is_empty());
+
+    let dataset = Dataset::new(
+        Matrix::new(3, 2, vec![1.0, 2.0, 3.0, f64::NAN, f64::INFINITY, 6.0]),
+        Vector::new(vec![0, 1, 0]),
+        Vector::new(vec!["a".to_string(), "b".to_string()]),
+        "label".to_string(),
+    );
+    assert!(dataset.has_non_finite());
+    assert_eq!(dataset.non_finite_locations(), vec![(1, 1), (2, 0)]);
+}
+
+#[test]
+fn quantile_test() {
+    let iris_dataset = iris::load();
+
+    assert_eq!(4.35, iris_dataset.quantile("PetalLengthCm", 0.5).unwrap());
+    assert_eq!(1.0, iris_dataset.quantile("PetalLengthCm", 0.0).unwrap());
+    assert_eq!(6.9, iris_dataset.quantile("PetalLengthCm", 1.0).unwrap());
+    assert_eq!(75.5, iris_dataset.quantile("Id", 0.5).unwrap());
+
+    let err = iris_dataset.quantile("PetalLengthCm", 1.5).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameters));
+    let err = iris_dataset.quantile("Petal", 0.5).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameters));
+}
+
+#[test]
+fn push_row_test() {
+    let mut iris_dataset = iris::load();
+
+    iris_dataset
+        .push_row(&[151.0, 5.0, 3.4, 1.5, 0.2], "Iris-setosa".to_string())
+        .unwrap();
+    assert_eq!(151, iris_dataset.data().rows());
+    assert_eq!(151, iris_dataset.target().size());
+    assert_eq!(
+        &[151.0, 5.0, 3.4, 1.5, 0.2],
+        iris_dataset.data().row(150).raw_slice()
+    );
+    assert_eq!("Iris-setosa", iris_dataset.target()[150]);
+    assert!(iris_dataset.validate().is_ok());
+
+    let err = iris_dataset
+        .push_row(&[1.0, 2.0], "Iris-setosa".to_string())
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidData));
+    assert_eq!(151, iris_dataset.data().rows());
+}
+
+#[test]
+fn encode_ordinal_target_test() {
+    let dataset = Dataset::new(
+        Matrix::new(4, 1, vec![1.0, 2.0, 3.0, 4.0]),
+        Vector::new(vec![
+            "med".to_string(),
+            "high".to_string(),
+            "low".to_string(),
+            "med".to_string(),
+        ]),
+        Vector::new(vec!["x".to_string()]),
+        "level".to_string(),
+    );
+
+    let (encoded, label_map) = dataset
+        .encode_ordinal_target(&["low", "med", "high"])
+        .unwrap();
+    assert_eq!(&Vector::new(vec![1.0, 2.0, 0.0, 1.0]), encoded.target());
+    assert_eq!(3, label_map.len());
+    assert_eq!(Some(&2.0), label_map.get("high"));
+
+    let err = dataset.encode_ordinal_target(&["low", "high"]).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidData));
+}
+
+#[test]
+fn from_csv_str_test() {
+    let csv = "a,b,label\n1.0,2.0,x\n3.0,4.0,y\n5.0,6.0,x\n";
+    let dataset: Dataset<Matrix<f64>, Vector<String>> =
+        Dataset::from_csv_str(csv, "label").unwrap();
+    assert_eq!(3, dataset.data().rows());
+    assert_eq!(2, dataset.data().cols());
+    assert_eq!(&[3.0, 4.0], dataset.data().row(1).raw_slice());
+    assert_eq!("y", dataset.target()[1]);
+
+    let mixed_csv = "size,color,label\n1.5,red,0\n2.0,blue,1\n2.5,red,0\n";
+    let mixed: MixedDataset<Vector<u8>> =
+        MixedDataset::from_csv_str(mixed_csv, "label", &["size"]).unwrap();
+    assert_eq!(3, mixed.data().len());
+    assert_eq!(2, mixed.data_columns().size());
+    assert_eq!(&MixedDataValue::Numeric(2.0), mixed.get(1, "size").unwrap());
+    assert_eq!(
+        &MixedDataValue::Categorical("blue".to_string()),
+        mixed.get(1, "color").unwrap()
+    );
+
+    assert!(Dataset::<Matrix<f64>, Vector<String>>::from_csv_str(csv, "missing").is_err());
+
+    let err =
+        Dataset::<Matrix<f64>, Vector<String>>::from_csv_str("a,b,label\n", "label").unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidData));
+    assert_eq!(err.to_string(), "CSV contains no data rows");
+}
+
+#[test]
+fn within_class_scatter_test() {
+    let iris_dataset = iris::load();
+    let n_features = iris_dataset.n_features();
+    let scatter = iris_dataset.within_class_scatter().unwrap();
+
+    assert_eq!(n_features, scatter.rows());
+    assert_eq!(n_features, scatter.cols());
+    for i in 0..n_features {
+        for j in 0..n_features {
+            assert!((scatter[[i, j]] - scatter[[j, i]]).abs() < 1e-9);
+        }
+        assert!(scatter[[i, i]] >= 0.0);
+    }
+
+    // The total scatter also counts the spread between the class means.
+    let mean = iris_dataset.data().mean(Axes::Row);
+    let centered = iris_dataset.data() - Matrix::new(150, n_features, mean.data().repeat(150));
+    let total_scatter = centered.transpose() * &centered;
+    assert!((0..n_features).any(|i| total_scatter[[i, i]] - scatter[[i, i]] > 1.0));
+}
+
+#[test]
+fn zscore_test() {
+    let iris_dataset = iris::load();
+    let standardized = iris_dataset.zscore().unwrap();
+
+    assert_eq!(iris_dataset.data_columns(), standardized.data_columns());
+    assert_eq!(iris_dataset.target(), standardized.target());
+    for idx in 0..standardized.n_features() {
+        let column = standardized.data().col(idx);
+        let mean = column.iter().sum::<f64>() / 150.0;
+        let variance = column.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / 150.0;
+        assert!(mean.abs() < 1e-9);
+        assert!((variance.sqrt() - 1.0).abs() < 1e-9);
+    }
+
+    // Constant columns are mapped to zero.
+    let dataset = Dataset::new(
+        Matrix::new(3, 2, vec![1.0, 0.1, 2.0, 0.1, 3.0, 0.1]),
+        Vector::new(vec![0.0, 1.0, 0.0]),
+        Vector::new(vec!["x".to_string(), "constant".to_string()]),
+        "label".to_string(),
+    );
+    let standardized = dataset.zscore().unwrap();
+    assert!(standardized.data().col(1).iter().all(|&v| v == 0.0));
+}
@@ -0,0 +1,86 @@
+use rust_ml::base::error::ErrorKind;
+use rust_ml::dataset::iris;
+use rust_ml::linalg::BaseMatrix;
+use rust_ml::preprocessing::decomposition::lda::{LDAFitter, LDA};
+use rust_ml::preprocessing::discretization::{
+    BinStrategy, KBinsDiscretizer, KBinsDiscretizerFitter,
+};
+use rust_ml::preprocessing::encoders::onehotencoder::OneHotDatasetTransform;
+use rust_ml::preprocessing::scalers::minmaxscaler::{MinMaxFitter, MinMaxScaler};
+use rust_ml::preprocessing::winsorize::{Winsorizer, WinsorizerFitter};
+use rust_ml::preprocessing::{DatasetTransform, FitStatus, PreprocessorFitter};
+
+#[test]
+fn dataset_transform_pipeline_test() {
+    let iris_dataset = iris::load();
+
+    let mut pipeline: Vec<Box<dyn DatasetTransform<String>>> = vec![
+        Box::new(Winsorizer::unfit_from(WinsorizerFitter::new(0.05, 0.95))),
+        Box::new(MinMaxScaler::unfit_from(MinMaxFitter::default())),
+        Box::new(LDA::unfit_from(LDAFitter::new(2))),
+    ];
+
+    let mut dataset = iris_dataset.clone();
+    for step in pipeline.iter_mut() {
+        step.fit(&dataset).unwrap();
+        dataset = step.transform(&dataset).unwrap();
+    }
+    assert_eq!(dataset.data().rows(), 150);
+    assert_eq!(dataset.data().cols(), 2);
+    assert_eq!(dataset.target(), iris_dataset.target());
+
+    // Fitting through the trait matches fitting through the fitter.
+    let mut scaler = MinMaxScaler::unfit_from(MinMaxFitter::new(-1.0, 1.0));
+    DatasetTransform::fit(&mut scaler, &iris_dataset).unwrap();
+    let fitted = MinMaxFitter::new(-1.0, 1.0).fit(&iris_dataset).unwrap();
+    assert_eq!(scaler.fitter().fit_status(), &FitStatus::Fit);
+    assert_eq!(
+        scaler.transform(&iris_dataset).unwrap().data(),
+        fitted.transform(&iris_dataset).unwrap().data()
+    );
+}
+
+#[test]
+fn dataset_transform_not_fit_test() {
+    let iris_dataset = iris::load();
+    let discretizer =
+        KBinsDiscretizer::unfit_from(KBinsDiscretizerFitter::new(3, BinStrategy::Quantile));
+
+    let err = discretizer.transform(&iris_dataset).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UntrainedModel));
+}
+
+#[test]
+fn dataset_transform_one_hot_test() {
+    let iris_dataset = iris::load();
+    let discretizer = KBinsDiscretizerFitter::new(3, BinStrategy::Uniform)
+        .fit(&iris_dataset)
+        .unwrap();
+    let binned_dataset = discretizer.transform(&iris_dataset).unwrap();
+
+    let mut pipeline: Vec<Box<dyn DatasetTransform<String>>> = vec![
+        Box::new(OneHotDatasetTransform::new(&["PetalLengthCm"]).with_sorted_categories(true)),
+        Box::new(MinMaxScaler::unfit_from(MinMaxFitter::default())),
+    ];
+    let mut dataset = binned_dataset.clone();
+    for step in pipeline.iter_mut() {
+        step.fit(&dataset).unwrap();
+        dataset = step.transform(&dataset).unwrap();
+    }
+    assert_eq!(dataset.data().rows(), 150);
+    assert_eq!(dataset.data().cols(), binned_dataset.data().cols() + 2);
+    for bin in 0..3 {
+        assert!(dataset
+            .data_columns()
+            .iter()
+            .any(|column| column == &format!("PetalLengthCm_{}", bin)));
+    }
+
+    let unfit = OneHotDatasetTransform::new(&["PetalLengthCm"]);
+    let err = unfit.transform(&binned_dataset).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::UntrainedModel));
+
+    let mut missing = OneHotDatasetTransform::new(&["Petals"]);
+    let err = missing.fit(&binned_dataset).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameters));
+}
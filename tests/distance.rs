@@ -0,0 +1,26 @@
+use rust_ml::linalg::{Matrix, Vector};
+use rust_ml::metrics::distance::{cosine_similarity, pairwise_cosine};
+
+#[test]
+fn cosine_similarity_test() {
+    let a = Vector::new(vec![1.0, 2.0, 3.0]);
+    let orthogonal = Vector::new(vec![3.0, 0.0, -1.0]);
+    let zero = Vector::new(vec![0.0, 0.0, 0.0]);
+
+    assert!((cosine_similarity(&a, &a).unwrap() - 1.0).abs() < 1e-12);
+    assert_eq!(cosine_similarity(&a, &orthogonal).unwrap(), 0.0);
+    assert!((cosine_similarity(&a, &(&a * -2.0)).unwrap() + 1.0).abs() < 1e-12);
+    assert_eq!(cosine_similarity(&a, &zero).unwrap(), 0.0);
+    assert!(cosine_similarity(&a, &Vector::new(vec![1.0])).is_err());
+}
+
+#[test]
+fn pairwise_cosine_test() {
+    let data = Matrix::new(3, 2, vec![1.0, 0.0, 0.0, 2.0, 1.0, 1.0]);
+    let similarities = pairwise_cosine(&data);
+
+    assert!((similarities[[0, 0]] - 1.0).abs() < 1e-12);
+    assert_eq!(similarities[[0, 1]], 0.0);
+    assert!((similarities[[0, 2]] - 0.5_f64.sqrt()).abs() < 1e-12);
+    assert_eq!(similarities[[2, 1]], similarities[[1, 2]]);
+}
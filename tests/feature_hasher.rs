@@ -0,0 +1,71 @@
+use rust_ml::base::error::ErrorKind;
+use rust_ml::dataset::{pokemon, MixedDataValue, MixedDataset};
+use rust_ml::linalg::{BaseMatrix, Vector};
+use rust_ml::preprocessing::encoders::feature_hasher::FeatureHasherFitter;
+use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+
+#[test]
+fn feature_hasher_test() {
+    let pokemon_dataset: MixedDataset<Vector<String>> = pokemon::load();
+    let n_features = 8;
+
+    let mut hasher = FeatureHasherFitter::new(n_features)
+        .fit(&pokemon_dataset)
+        .unwrap();
+    let hashed_dataset = hasher.transform(&pokemon_dataset).unwrap();
+
+    assert_eq!(hasher.fitter().fit_status(), &FitStatus::Fit);
+    assert_eq!(hasher.fitter().hashed_columns(), vec!["Type 1", "Type 2"]);
+    assert_eq!(hashed_dataset.data().rows(), 800);
+    assert_eq!(hashed_dataset.data().cols(), 9 + 2 * n_features);
+    for name in ["Type 1", "Type 2"] {
+        let hashed_columns = hashed_dataset
+            .data_columns()
+            .iter()
+            .filter(|c| c.starts_with(&format!("{}_hash_", name)))
+            .count();
+        assert_eq!(hashed_columns, n_features);
+    }
+
+    // Each categorical value lands in exactly one bucket of its column.
+    let type_1_start = hashed_dataset
+        .data_columns()
+        .iter()
+        .position(|c| c == "Type 1_hash_0")
+        .unwrap();
+    for row in hashed_dataset.data().row_iter() {
+        let buckets = &row.raw_slice()[type_1_start..type_1_start + n_features];
+        assert_eq!(buckets.iter().sum::<f64>(), 1.0);
+    }
+
+    // FNV-1a 64 of "Grass" is 17238504558144415869, which is bucket 5 of 8.
+    let type_1_index = pokemon_dataset
+        .data_columns()
+        .iter()
+        .position(|c| c == "Type 1")
+        .unwrap();
+    let grass_row = pokemon_dataset
+        .data()
+        .iter()
+        .position(|row| row[type_1_index] == MixedDataValue::Categorical("Grass".to_string()))
+        .unwrap();
+    let grass_bucket = hashed_dataset
+        .data_columns()
+        .iter()
+        .position(|c| c == "Type 1_hash_5")
+        .unwrap();
+    assert_eq!(hashed_dataset.data()[[grass_row, grass_bucket]], 1.0);
+
+    // The hash is stable, so a fresh hasher produces the same encoding.
+    let mut other_hasher = FeatureHasherFitter::new(n_features)
+        .fit(&pokemon_dataset)
+        .unwrap();
+    let other_dataset = other_hasher.transform(&pokemon_dataset).unwrap();
+    assert_eq!(hashed_dataset.data(), other_dataset.data());
+    assert_eq!(hashed_dataset.data_columns(), other_dataset.data_columns());
+
+    let err = FeatureHasherFitter::<String>::new(0)
+        .fit(&pokemon_dataset)
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidParameters));
+}
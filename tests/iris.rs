@@ -18,3 +18,59 @@ fn iris_test() {
     );
     assert_eq!("Species", iris_dataset.target_column());
 }
+
+#[test]
+fn iris_target_slice_test() {
+    let iris_dataset = iris::load();
+    let target = iris_dataset.target_slice();
+    assert_eq!(150, target.len());
+    assert_eq!("Iris-setosa", target[0]);
+    assert_eq!(iris_dataset.target().data().as_slice(), target);
+}
+
+#[test]
+fn iris_counts_test() {
+    let iris_dataset = iris::load();
+    assert_eq!(5, iris_dataset.n_features());
+    assert_eq!(150, iris_dataset.n_samples());
+    assert_eq!(3, iris_dataset.n_classes());
+}
+
+#[test]
+fn iris_binarize_target_test() {
+    let iris_dataset = iris::load();
+    let binary_dataset = iris_dataset.binarize_target("Iris-setosa");
+
+    assert_eq!(150, binary_dataset.target().size());
+    assert_eq!(50.0, binary_dataset.target().sum());
+    assert_eq!(1.0, binary_dataset.target()[0]);
+    assert_eq!(0.0, binary_dataset.target()[149]);
+    assert_eq!(iris_dataset.data(), binary_dataset.data());
+}
+
+#[test]
+fn iris_memory_bytes_test() {
+    let iris_dataset = iris::load();
+    let memory = iris_dataset.memory_bytes();
+
+    // 150 x 5 feature values take 6000 bytes, the labels add about 2000 more.
+    assert!(memory > 8000 && memory < 8200);
+
+    // An f64 target takes 8 bytes per sample instead of the label lengths.
+    let binary_dataset = iris_dataset.binarize_target("Iris-setosa");
+    assert_eq!(memory - 2000 + 1200, binary_dataset.memory_bytes());
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn iris_gz_test() {
+    use rust_ml::dataset::Dataset;
+    use rust_ml::linalg::Matrix;
+
+    let iris_dataset = iris::load();
+    let iris_gz_dataset: Dataset<Matrix<f64>, Vector<String>> =
+        Dataset::from_csv_gz("./tests/data/iris.csv.gz", "Species").unwrap();
+    assert_eq!(iris_dataset.data().rows(), iris_gz_dataset.data().rows());
+    assert_eq!(iris_dataset.data().cols(), iris_gz_dataset.data().cols());
+    assert_eq!(iris_dataset.data_columns(), iris_gz_dataset.data_columns());
+}
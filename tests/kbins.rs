@@ -0,0 +1,53 @@
+use rust_ml::dataset::{iris, Dataset};
+use rust_ml::linalg::{BaseMatrix, Matrix, Vector};
+use rust_ml::preprocessing::discretization::{BinStrategy, KBinsDiscretizerFitter};
+use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+
+#[test]
+fn kbins_discretizer_test() {
+    let iris_dataset = iris::load();
+
+    let kbins_fitter = KBinsDiscretizerFitter::new(4, BinStrategy::Uniform);
+    let mut kbins = kbins_fitter.fit(&iris_dataset).unwrap();
+    let binned_dataset = kbins.transform(&iris_dataset).unwrap();
+
+    // PetalLengthCm ranges from 1.0 to 6.9.
+    let petal_length_bins: Vec<f64> = binned_dataset.data().col(3).iter().copied().collect();
+    for bin in [0.0, 1.0, 2.0, 3.0] {
+        assert!(petal_length_bins.contains(&bin));
+    }
+    assert!(petal_length_bins
+        .iter()
+        .all(|b| [0.0, 1.0, 2.0, 3.0].contains(b)));
+    assert_eq!(kbins.fitter().fit_status(), &FitStatus::Fit);
+    assert_eq!(kbins.fitter().bin_edges()[3].len(), 5);
+
+    // Values outside the fitted range clamp to the edge bins.
+    let out_of_range = Dataset::new(
+        Matrix::new(
+            2,
+            5,
+            vec![-10.0, 0.0, 0.0, 0.0, 0.0, 500.0, 20.0, 20.0, 20.0, 20.0],
+        ),
+        Vector::new(vec!["a".to_string(), "b".to_string()]),
+        iris_dataset.data_columns().clone(),
+        "Species".to_string(),
+    );
+    let clamped = kbins.transform(&out_of_range).unwrap();
+    assert_eq!(
+        clamped.data().data(),
+        &vec![0.0, 0.0, 0.0, 0.0, 0.0, 3.0, 3.0, 3.0, 3.0, 3.0]
+    );
+
+    let mut quantile_kbins = KBinsDiscretizerFitter::new(2, BinStrategy::Quantile)
+        .fit(&iris_dataset)
+        .unwrap();
+    let quantile_binned = quantile_kbins.transform(&iris_dataset).unwrap();
+    let upper_half = quantile_binned
+        .data()
+        .col(0)
+        .iter()
+        .filter(|&&b| b == 1.0)
+        .count();
+    assert_eq!(upper_half, 75);
+}
@@ -1,6 +1,6 @@
-use rust_ml::dataset::iris;
-use rust_ml::linalg::Vector;
-use rust_ml::preprocessing::encoders::labelencoder::LabelEncoderFitter;
+use rust_ml::dataset::{iris, Dataset};
+use rust_ml::linalg::{Matrix, Vector};
+use rust_ml::preprocessing::encoders::labelencoder::{LabelEncoderFitter, UnknownLabelPolicy};
 use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
 use std::collections::HashMap;
 
@@ -34,3 +34,146 @@ fn labelencoder_test() {
     assert_eq!(mapped_labels.size(), 150);
     assert_eq!(mapped_labels, test_vec);
 }
+
+#[test]
+fn labelencoder_unknown_policy_test() {
+    let train = Vector::new(vec!["a".to_string(), "b".to_string(), "a".to_string()]);
+    let test = Vector::new(vec!["b".to_string(), "z".to_string(), "a".to_string()]);
+
+    let mut error_encoder = LabelEncoderFitter::<String, f64>::default()
+        .fit(&train)
+        .unwrap();
+    assert!(error_encoder.transform(&test).is_err());
+
+    let mut skip_encoder = LabelEncoderFitter::<String, f64>::default()
+        .with_unknown(UnknownLabelPolicy::Skip)
+        .fit(&train)
+        .unwrap();
+    let (skipped, kept_indices) = skip_encoder.transform_with_indices(&test).unwrap();
+    assert_eq!(skipped, Vector::new(vec![1.0, 0.0]));
+    assert_eq!(kept_indices, vec![0, 2]);
+    assert_eq!(skip_encoder.transform(&test).unwrap(), skipped);
+
+    let mut assign_encoder = LabelEncoderFitter::<String, f64>::default()
+        .with_unknown(UnknownLabelPolicy::Assign(-1.0))
+        .fit(&train)
+        .unwrap();
+    assert_eq!(
+        assign_encoder.transform(&test).unwrap(),
+        Vector::new(vec![1.0, -1.0, 0.0])
+    );
+    assert_eq!(
+        assign_encoder.fitter().unknown_policy(),
+        &UnknownLabelPolicy::Assign(-1.0)
+    );
+}
+
+#[test]
+fn labelencoder_fit_transform_dataset_test() {
+    let iris_dataset = iris::load();
+    let columns = iris_dataset.data_columns().clone();
+
+    let (encoded_dataset, label_encoder) = LabelEncoderFitter::<String, f64>::default()
+        .fit_transform_dataset(iris_dataset)
+        .unwrap();
+
+    assert_eq!(label_encoder.fitter().fit_status(), &FitStatus::Fit);
+    assert_eq!(label_encoder.fitter().label_map().len(), 3);
+    assert_eq!(encoded_dataset.target().size(), 150);
+    assert_eq!(encoded_dataset.target()[0], 0.0);
+    assert_eq!(encoded_dataset.target()[149], 2.0);
+    assert_eq!(encoded_dataset.data_columns(), &columns);
+}
+
+#[test]
+fn labelencoder_transform_into_dataset_test() {
+    let iris_dataset = iris::load();
+    let data = iris_dataset.data().clone();
+    let columns = iris_dataset.data_columns().clone();
+
+    let mut label_encoder = LabelEncoderFitter::<String, f64>::default()
+        .fit(iris_dataset.target())
+        .unwrap();
+    let encoded_dataset = label_encoder.transform_into_dataset(iris_dataset).unwrap();
+
+    assert_eq!(encoded_dataset.data(), &data);
+    assert_eq!(encoded_dataset.data_columns(), &columns);
+    assert_eq!(encoded_dataset.target_column(), "Species");
+    assert_eq!(encoded_dataset.target()[0], 0.0);
+    assert_eq!(encoded_dataset.target()[75], 1.0);
+    assert_eq!(encoded_dataset.target()[149], 2.0);
+
+    // Rows with skipped labels are dropped from the feature matrix too.
+    let train = Vector::new(vec!["a".to_string(), "b".to_string()]);
+    let mut skip_encoder = LabelEncoderFitter::<String, f64>::default()
+        .with_unknown(UnknownLabelPolicy::Skip)
+        .fit(&train)
+        .unwrap();
+    let dataset = Dataset::new(
+        Matrix::new(3, 1, vec![1.0, 2.0, 3.0]),
+        Vector::new(vec!["b".to_string(), "z".to_string(), "a".to_string()]),
+        Vector::new(vec!["x".to_string()]),
+        "label".to_string(),
+    );
+    let skipped_dataset = skip_encoder.transform_into_dataset(dataset).unwrap();
+    assert_eq!(skipped_dataset.data(), &Matrix::new(2, 1, vec![1.0, 3.0]));
+    assert_eq!(skipped_dataset.target(), &Vector::new(vec![1.0, 0.0]));
+}
+
+#[test]
+fn labelencoder_merge_test() {
+    let first_split = Vector::new(vec![
+        "cat".to_string(),
+        "dog".to_string(),
+        "cat".to_string(),
+    ]);
+    let second_split = Vector::new(vec![
+        "eel".to_string(),
+        "bat".to_string(),
+        "dog".to_string(),
+    ]);
+
+    let first = LabelEncoderFitter::<String, f64>::default()
+        .fit(&first_split)
+        .unwrap();
+    let second = LabelEncoderFitter::<String, f64>::default()
+        .fit(&second_split)
+        .unwrap();
+    // "dog" has code 1 in the first encoder and code 2 in the second.
+    let merged = first.fitter().clone().merge(second.fitter()).unwrap();
+
+    let mut expected = HashMap::<String, f64>::new();
+    expected.insert("bat".to_string(), 0.0);
+    expected.insert("cat".to_string(), 1.0);
+    expected.insert("dog".to_string(), 2.0);
+    expected.insert("eel".to_string(), 3.0);
+    assert_eq!(merged.label_map(), &expected);
+    assert_eq!(merged.fit_status(), &FitStatus::Fit);
+
+    let unfit = LabelEncoderFitter::<String, f64>::default();
+    assert!(unfit.merge(second.fitter()).is_err());
+}
+
+#[test]
+fn labelencoder_nan_value_test() {
+    let train = Vector::new(vec!["a".to_string(), "b".to_string()]);
+    let test = Vector::new(vec!["a".to_string(), "unseen".to_string(), "b".to_string()]);
+
+    let default_fitter = LabelEncoderFitter::<String, f64>::default();
+    assert_eq!(default_fitter.nan_value(), None);
+    assert!(default_fitter
+        .fit(&train)
+        .unwrap()
+        .transform(&test)
+        .is_err());
+
+    let mut sentinel_encoder = LabelEncoderFitter::<String, f64>::default()
+        .with_nan_value(-1.0)
+        .fit(&train)
+        .unwrap();
+    assert_eq!(sentinel_encoder.fitter().nan_value(), Some(&-1.0));
+    assert_eq!(
+        sentinel_encoder.transform(&test).unwrap(),
+        Vector::new(vec![0.0, -1.0, 1.0])
+    );
+}
@@ -0,0 +1,72 @@
+use rust_ml::dataset::{iris, Dataset};
+use rust_ml::linalg::{Axes, BaseMatrix, Vector};
+use rust_ml::preprocessing::decomposition::lda::LDAFitter;
+use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+
+#[test]
+fn lda_test() {
+    // The Id column is sorted by class, so it is dropped to keep it from
+    // separating the classes on its own.
+    let iris = iris::load();
+    let iris_dataset = Dataset::new(
+        iris.data().select_cols(&[1, 2, 3, 4]),
+        iris.target().clone(),
+        Vector::new(iris.data_columns().data()[1..].to_vec()),
+        iris.target_column().to_string(),
+    );
+
+    let lda_fitter = LDAFitter::new(2);
+    let mut lda = lda_fitter.fit(&iris_dataset).unwrap();
+    let reduced_dataset = lda.transform(&iris_dataset).unwrap();
+
+    assert_eq!(lda.fitter().fit_status(), &FitStatus::Fit);
+    assert_eq!(reduced_dataset.data().rows(), 150);
+    assert_eq!(
+        reduced_dataset.data_columns(),
+        &Vector::new(vec!["LD1".to_string(), "LD2".to_string()])
+    );
+    assert!(lda.fitter().eigenvalues()[0] >= lda.fitter().eigenvalues()[1]);
+
+    // Setosa occupies its own interval along the first component, and almost
+    // every sample is closest to its own class mean in the reduced space.
+    let first_component: Vec<f64> = reduced_dataset.data().col(0).iter().copied().collect();
+    let mut ranges: Vec<(f64, f64)> = first_component
+        .chunks(50)
+        .map(|class| {
+            let min = class.iter().cloned().fold(f64::MAX, f64::min);
+            let max = class.iter().cloned().fold(f64::MIN, f64::max);
+            (min, max)
+        })
+        .collect();
+    let setosa = ranges.remove(0);
+    assert!(ranges.iter().all(|r| setosa.1 < r.0 || r.1 < setosa.0));
+
+    let means: Vec<Vec<f64>> = (0..3)
+        .map(|class| {
+            let rows = reduced_dataset
+                .data()
+                .select_rows(&(class * 50..(class + 1) * 50).collect::<Vec<usize>>());
+            rows.mean(Axes::Row).into_vec()
+        })
+        .collect();
+    let correct = reduced_dataset
+        .data()
+        .row_iter()
+        .enumerate()
+        .filter(|(idx, row)| {
+            let distance = |mean: &Vec<f64>| -> f64 {
+                row.iter()
+                    .zip(mean.iter())
+                    .map(|(x, m)| (x - m).powi(2))
+                    .sum()
+            };
+            let nearest = (0..3)
+                .min_by(|&a, &b| distance(&means[a]).total_cmp(&distance(&means[b])))
+                .unwrap();
+            nearest == idx / 50
+        })
+        .count();
+    assert!(correct >= 147);
+
+    assert!(LDAFitter::<String>::new(3).fit(&iris_dataset).is_err());
+}
@@ -0,0 +1,286 @@
+use rust_ml::dataset::{iris, Dataset};
+use rust_ml::linalg::{BaseMatrix, Matrix, Vector};
+use rust_ml::metrics::{
+    adjusted_r2_score, classification_report, entropy, gini_impurity, information_gain, log_loss,
+    mean_absolute_error, median_absolute_error, mutual_info_classif, predictions_to_csv, r2_score,
+    residuals, roc_auc_score, roc_curve, silhouette_score, standardized_residuals,
+};
+
+#[test]
+fn roc_curve_test() {
+    let y_true = Vector::new(vec![0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0]);
+    let y_score = Vector::new(vec![0.1, 0.3, 0.35, 0.4, 0.6, 0.7, 0.2, 0.9]);
+    let (fpr, tpr, thresholds) = roc_curve(&y_true, &y_score).unwrap();
+
+    assert_eq!(fpr.len(), 9);
+    assert_eq!(tpr.len(), 9);
+    assert_eq!(thresholds.len(), 9);
+    assert_eq!((fpr[8], tpr[8]), (1.0, 1.0));
+
+    // A higher threshold never predicts more positives than a lower one.
+    let positive_count = |threshold: f64| y_score.iter().filter(|&&s| s >= threshold).count();
+    for window in thresholds[1..].windows(2) {
+        assert!(window[0] > window[1]);
+        assert!(positive_count(window[0]) < positive_count(window[1]));
+    }
+    for i in 1..fpr.len() {
+        assert!(fpr[i] >= fpr[i - 1]);
+        assert!(tpr[i] >= tpr[i - 1]);
+    }
+
+    assert!(roc_curve(&Vector::new(vec![0.0, 2.0]), &Vector::new(vec![0.1, 0.2])).is_err());
+    assert!(roc_curve(&Vector::new(vec![0.0, 1.0]), &Vector::new(vec![0.1])).is_err());
+}
+
+#[test]
+fn roc_auc_score_test() {
+    let y_true = Vector::new(vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+    let separating_scores = Vector::new(vec![0.1, 0.2, 0.3, 0.7, 0.8, 0.9]);
+    assert_eq!(roc_auc_score(&y_true, &separating_scores).unwrap(), 1.0);
+
+    let tied_scores = Vector::new(vec![0.5; 6]);
+    assert!((roc_auc_score(&y_true, &tied_scores).unwrap() - 0.5).abs() < 1e-12);
+
+    let one_class = Vector::new(vec![1.0; 6]);
+    assert!(roc_auc_score(&one_class, &separating_scores).is_err());
+    let not_binary = Vector::new(vec![0.0, 0.0, 0.0, 1.0, 1.0, 2.0]);
+    assert!(roc_auc_score(&not_binary, &separating_scores).is_err());
+}
+
+#[test]
+fn mutual_info_classif_test() {
+    // Column "informative" separates the classes, "noise" alternates independently.
+    let dataset = Dataset::new(
+        Matrix::new(
+            8,
+            2,
+            vec![
+                0.0, 0.1, 1.0, 0.2, 0.0, 0.15, 1.0, 0.05, 0.0, 0.9, 1.0, 0.95, 0.0, 0.85, 1.0, 0.8,
+            ],
+        ),
+        Vector::new(vec!["a", "a", "a", "a", "b", "b", "b", "b"]),
+        Vector::new(vec!["noise".to_string(), "informative".to_string()]),
+        "label".to_string(),
+    );
+    let scores = mutual_info_classif(&dataset, 2).unwrap();
+
+    assert_eq!(scores[0].0, "informative");
+    assert!((scores[0].1 - 2.0_f64.ln()).abs() < 1e-12);
+    assert_eq!(scores[1].0, "noise");
+    assert!(scores[1].1.abs() < 1e-12);
+}
+
+#[test]
+fn silhouette_score_test() {
+    let labels = Vector::new(vec![0, 0, 0, 1, 1, 1]);
+    let separated = Matrix::new(
+        6,
+        2,
+        vec![
+            0.0, 0.0, 0.1, 0.0, 0.0, 0.1, 10.0, 10.0, 10.1, 10.0, 10.0, 10.1,
+        ],
+    );
+    let overlapping = Matrix::new(
+        6,
+        2,
+        vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.5, 0.5, 1.5, 0.5, 0.5, 1.5],
+    );
+
+    let separated_score = silhouette_score(&separated, &labels).unwrap();
+    let overlapping_score = silhouette_score(&overlapping, &labels).unwrap();
+    assert!(separated_score > 0.95);
+    assert!(overlapping_score < separated_score);
+    assert!(overlapping_score < 0.5);
+
+    let single_cluster = Vector::new(vec![0; 6]);
+    assert_eq!(silhouette_score(&separated, &single_cluster).unwrap(), 0.0);
+    let singletons = Vector::new(vec![0, 0, 0, 1, 1, 2]);
+    assert!(silhouette_score(&separated, &singletons)
+        .unwrap()
+        .is_finite());
+    assert!(silhouette_score(&separated, &Vector::new(vec![0, 1])).is_err());
+}
+
+#[test]
+fn residuals_test() {
+    let y_true = Vector::new(vec![3.0, -0.5, 2.0, 7.0, 4.5]);
+    let y_pred = Vector::new(vec![2.5, 0.0, 2.0, 8.0, 3.5]);
+
+    let values = residuals(&y_true, &y_pred).unwrap();
+    assert_eq!(values, Vector::new(vec![0.5, -0.5, 0.0, -1.0, 1.0]));
+
+    let standardized = standardized_residuals(&y_true, &y_pred).unwrap();
+    let n = standardized.size() as f64;
+    let mean = standardized.sum() / n;
+    let variance = standardized.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / n;
+    assert!(mean.abs() < 1e-12);
+    assert!((variance - 1.0).abs() < 1e-12);
+
+    assert!(residuals(&y_true, &Vector::new(vec![1.0])).is_err());
+    assert!(standardized_residuals(&y_true, &(&y_true - 1.0)).is_err());
+}
+
+#[test]
+fn information_gain_test() {
+    // "split" separates the classes perfectly, "noise" repeats the same values
+    // for both classes.
+    let dataset = Dataset::new(
+        Matrix::new(
+            8,
+            2,
+            vec![
+                1.0, 0.0, 2.0, 0.1, 3.0, 0.2, 4.0, 0.3, 1.0, 0.9, 2.0, 1.0, 3.0, 1.1, 4.0, 1.2,
+            ],
+        ),
+        Vector::new(vec!["a", "a", "a", "a", "b", "b", "b", "b"]),
+        Vector::new(vec!["noise".to_string(), "split".to_string()]),
+        "class".to_string(),
+    );
+
+    let gains = information_gain(&dataset, 2).unwrap();
+    assert_eq!("split", gains[0].0);
+    assert!((gains[0].1 - 1.0).abs() < 1e-12);
+    assert_eq!("noise", gains[1].0);
+    assert!(gains[1].1.abs() < 1e-12);
+}
+
+#[test]
+fn classification_report_test() {
+    let y_true = Vector::new(vec![
+        "cat", "cat", "cat", "dog", "dog", "bird", "bird", "bird",
+    ]);
+    let y_pred = Vector::new(vec![
+        "cat", "cat", "dog", "dog", "cat", "bird", "bird", "dog",
+    ]);
+
+    let report = classification_report(&y_true, &y_pred).unwrap();
+    let labels: Vec<&str> = report.iter().map(|r| *r.label()).collect();
+    assert_eq!(labels, vec!["bird", "cat", "dog"]);
+
+    // cat: 2 of the 3 cat predictions are right and 2 of the 3 cats are found.
+    let cat = &report[1];
+    assert!((cat.precision() - 2.0 / 3.0).abs() < 1e-12);
+    assert!((cat.recall() - 2.0 / 3.0).abs() < 1e-12);
+    assert!((cat.f1() - 2.0 / 3.0).abs() < 1e-12);
+    assert_eq!(cat.support(), 3);
+
+    // dog: 1 of the 3 dog predictions is right and 1 of the 2 dogs is found.
+    let dog = &report[2];
+    assert!((dog.precision() - 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(dog.recall(), 0.5);
+    assert!((dog.f1() - 0.4).abs() < 1e-12);
+    assert_eq!(dog.support(), 2);
+
+    assert_eq!(report[0].precision(), 1.0);
+    assert!(classification_report(&y_true, &Vector::new(vec!["cat"])).is_err());
+}
+
+#[test]
+fn absolute_error_test() {
+    let y_true = Vector::new(vec![3.0, -0.5, 2.0, 7.0, 10.0]);
+    let y_pred = Vector::new(vec![2.5, 0.0, 2.0, 8.0, 4.0]);
+
+    // Absolute residuals are 0.5, 0.5, 0.0, 1.0 and 6.0.
+    assert!((mean_absolute_error(&y_true, &y_pred).unwrap() - 1.6).abs() < 1e-12);
+    assert_eq!(median_absolute_error(&y_true, &y_pred).unwrap(), 0.5);
+
+    let even_true = Vector::new(vec![1.0, 2.0, 3.0, 4.0]);
+    let even_pred = Vector::new(vec![2.0, 4.0, 6.0, 8.0]);
+    assert_eq!(median_absolute_error(&even_true, &even_pred).unwrap(), 2.5);
+
+    let empty = Vector::new(Vec::<f64>::new());
+    assert!(mean_absolute_error(&empty, &empty).is_err());
+    assert!(median_absolute_error(&y_true, &even_pred).is_err());
+}
+
+#[test]
+fn adjusted_r2_score_test() {
+    let y_true = Vector::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    let y_pred = Vector::new(vec![1.5, 1.5, 3.5, 3.5, 5.5, 5.5, 7.5, 7.5]);
+
+    // Residual sum of squares is 8 * 0.25 = 2 and the total sum of squares 42.
+    let r2 = r2_score(&y_true, &y_pred).unwrap();
+    assert!((r2 - (1.0 - 2.0 / 42.0)).abs() < 1e-12);
+
+    let adjusted_small = adjusted_r2_score(&y_true, &y_pred, 1).unwrap();
+    let adjusted_large = adjusted_r2_score(&y_true, &y_pred, 5).unwrap();
+    assert!((adjusted_small - (1.0 - (2.0 / 42.0) * 7.0 / 6.0)).abs() < 1e-12);
+    assert!(adjusted_small < r2);
+    assert!(adjusted_large < adjusted_small);
+
+    assert!(adjusted_r2_score(&y_true, &y_pred, 7).is_err());
+    assert!(r2_score(&Vector::new(vec![1.0, 1.0]), &Vector::new(vec![1.0, 2.0])).is_err());
+}
+
+#[test]
+fn gini_impurity_entropy_test() {
+    let pure = Vector::new(vec!["a", "a", "a"]);
+    assert_eq!(gini_impurity(&pure), 0.0);
+    assert_eq!(entropy(&pure), 0.0);
+
+    let balanced = Vector::new(vec![0, 1, 1, 0]);
+    assert_eq!(gini_impurity(&balanced), 0.5);
+    assert_eq!(entropy(&balanced), 1.0);
+
+    let three_classes = Vector::new(vec![0, 1, 2]);
+    assert!((gini_impurity(&three_classes) - 2.0 / 3.0).abs() < 1e-12);
+    assert!((entropy(&three_classes) - 3.0_f64.log2()).abs() < 1e-12);
+
+    let empty = Vector::new(Vec::<usize>::new());
+    assert_eq!(gini_impurity(&empty), 0.0);
+    assert_eq!(entropy(&empty), 0.0);
+}
+
+#[test]
+fn log_loss_test() {
+    let y_true = Vector::new(vec![1.0, 0.0, 1.0, 0.0]);
+
+    let confident = Vector::new(vec![0.999, 0.001, 0.999, 0.001]);
+    assert!(log_loss(&y_true, &confident).unwrap() < 0.01);
+
+    let wrong = Vector::new(vec![0.0, 1.0, 0.0, 1.0]);
+    let wrong_loss = log_loss(&y_true, &wrong).unwrap();
+    assert!(wrong_loss.is_finite());
+    assert!(wrong_loss > 30.0);
+
+    let uninformed = Vector::new(vec![0.5, 0.5, 0.5, 0.5]);
+    assert!((log_loss(&y_true, &uninformed).unwrap() - 2.0_f64.ln()).abs() < 1e-12);
+
+    assert!(log_loss(&y_true, &Vector::new(vec![0.5, 0.5])).is_err());
+    assert!(log_loss(&Vector::new(vec![1.0, 2.0]), &Vector::new(vec![0.5, 0.5])).is_err());
+}
+
+#[test]
+fn predictions_to_csv_test() {
+    let dataset = iris::load().binarize_target("Iris-setosa");
+    // Predict setosa for the first 60 rows, so rows 50..60 are wrong.
+    let predictions = Vector::new(
+        (0..150)
+            .map(|i| if i < 60 { 1.0 } else { 0.0 })
+            .collect::<Vec<f64>>(),
+    );
+    let path = std::env::temp_dir().join("rust_ml_predictions_to_csv_test.csv");
+
+    predictions_to_csv(&dataset, &predictions, &path).unwrap();
+    let reloaded: Dataset<Matrix<f64>, Vector<f64>> =
+        Dataset::from_csv(&path, "prediction").unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(reloaded.target(), &predictions);
+    assert_eq!(
+        reloaded.data_columns().size(),
+        dataset.data_columns().size() + 1
+    );
+    assert_eq!(reloaded.data_columns()[5], "Species");
+    assert_eq!(
+        reloaded.data().select_cols(&[0, 1, 2, 3, 4]),
+        *dataset.data()
+    );
+    assert_eq!(
+        reloaded.data().col(5).iter().copied().collect::<Vec<f64>>(),
+        *dataset.target().data()
+    );
+
+    let short = Vector::new(vec![1.0; 10]);
+    assert!(predictions_to_csv(&dataset, &short, &path).is_err());
+}
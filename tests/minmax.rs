@@ -1,5 +1,7 @@
-use rust_ml::dataset::iris;
-use rust_ml::preprocessing::scalers::minmaxscaler::MinMaxFitter;
+use rust_ml::base::error::ErrorKind;
+use rust_ml::dataset::{iris, Dataset};
+use rust_ml::linalg::{Axes, BaseMatrix, Matrix, Vector};
+use rust_ml::preprocessing::scalers::minmaxscaler::{MinMaxFitter, MinMaxParams};
 use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
 
 #[test]
@@ -9,10 +11,16 @@ fn minmaxscaler_test() {
     let minmax_fitter = MinMaxFitter::default();
     let mut minmax_scaler = minmax_fitter.fit(&iris_dataset).unwrap();
     let transformed_dataset = minmax_scaler.transform(&iris_dataset).unwrap();
-    
+
     let min_values = vec![1.0, 4.3, 2.0, 1.0, 0.1];
     let max_values = vec![150.0, 7.9, 4.4, 6.9, 2.5];
-    let first_row = &[0.0, 0.2222222222222221, 0.625, 0.06779661016949151, 0.04166666666666667];
+    let first_row = &[
+        0.0,
+        0.2222222222222221,
+        0.625,
+        0.06779661016949151,
+        0.04166666666666667,
+    ];
     let transformed_first_row = &transformed_dataset.data().data()[0..5];
 
     assert_eq!(minmax_scaler.fitter().min_values(), &min_values);
@@ -20,3 +28,238 @@ fn minmaxscaler_test() {
     assert_eq!(minmax_scaler.fitter().fit_status(), &FitStatus::Fit);
     assert_eq!(transformed_first_row, first_row);
 }
+
+#[test]
+fn minmaxscaler_column_mismatch_test() {
+    let iris_dataset = iris::load();
+    let mut columns: Vec<String> = iris_dataset.data_columns().iter().cloned().collect();
+    columns[2] = "SepalWidthInches".to_string();
+    let renamed_dataset = Dataset::new(
+        iris_dataset.data().clone(),
+        iris_dataset.target().clone(),
+        Vector::new(columns),
+        iris_dataset.target_column().to_string(),
+    );
+
+    let mut minmax_scaler = MinMaxFitter::default().fit(&iris_dataset).unwrap();
+    let error = minmax_scaler.transform(&renamed_dataset).unwrap_err();
+
+    assert!(matches!(error.kind(), ErrorKind::InvalidState));
+    assert_eq!(
+        error.to_string(),
+        "Dataset column SepalWidthInches at position 2 does not match the fitted column SepalWidthCm"
+    );
+}
+
+#[test]
+fn minmaxscaler_transform_matrix_test() {
+    let iris_dataset = iris::load();
+    let minmax_scaler = MinMaxFitter::default().fit(&iris_dataset).unwrap();
+
+    let raw_row = Matrix::new(1, 5, vec![1.0, 5.1, 3.5, 1.4, 0.2]);
+    let scaled_row = minmax_scaler.transform_matrix(&raw_row).unwrap();
+    let expected = &[
+        0.0,
+        0.2222222222222221,
+        0.625,
+        0.06779661016949151,
+        0.04166666666666667,
+    ];
+    assert_eq!(scaled_row.rows(), 1);
+    assert_eq!(scaled_row.data().as_slice(), expected);
+
+    let err = minmax_scaler
+        .transform_matrix(&Matrix::new(1, 2, vec![1.0, 2.0]))
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidState));
+}
+
+#[test]
+fn minmaxscaler_summary_test() {
+    let iris_dataset = iris::load();
+    let minmax_scaler = MinMaxFitter::default().fit(&iris_dataset).unwrap();
+
+    let summary = minmax_scaler.summary();
+    assert_eq!(
+        summary.lines().next().unwrap(),
+        "MinMaxScaler (Fit), scaled range [0, 1]"
+    );
+    assert_eq!(summary.lines().count(), 7);
+    let sepal_length = summary
+        .lines()
+        .find(|line| line.starts_with("SepalLengthCm"))
+        .unwrap();
+    assert!(sepal_length.contains("4.3"));
+    assert!(sepal_length.contains("7.9"));
+}
+
+#[test]
+fn minmaxscaler_quantile_range_test() {
+    let mut values: Vec<f64> = (1..=98).map(|v| v as f64).collect();
+    values.extend([1000.0, 2000.0]);
+    let dataset = Dataset::new(
+        Matrix::new(100, 1, values),
+        Vector::new(vec![0.0; 100]),
+        Vector::new(vec!["x".to_string()]),
+        "y".to_string(),
+    );
+
+    let plain = MinMaxFitter::default().fit(&dataset).unwrap();
+    let robust = MinMaxFitter::default()
+        .with_quantile_range(0.05, 0.95)
+        .fit(&dataset)
+        .unwrap();
+    let plain_scaled = plain.transform_matrix(dataset.data()).unwrap();
+    let robust_scaled = robust.transform_matrix(dataset.data()).unwrap();
+
+    // The bulk of the values spans most of the range instead of being squeezed
+    // near zero by the outlier, which is clamped to the scaled maximum.
+    let spread = |m: &Matrix<f64>| m.data()[89] - m.data()[5];
+    assert!(spread(&plain_scaled) < 0.05);
+    assert!(spread(&robust_scaled) > 0.9);
+    assert_eq!(robust_scaled.data()[99], 1.0);
+    assert!(robust_scaled
+        .data()
+        .iter()
+        .all(|&v| (0.0..=1.0).contains(&v)));
+    assert_eq!(robust.fitter().quantile_range(), Some((0.05, 0.95)));
+
+    let invalid = MinMaxFitter::default()
+        .with_quantile_range(0.9, 0.1)
+        .fit(&dataset);
+    assert!(invalid.is_err());
+    let empty_dataset = Dataset::new(
+        Matrix::new(0, 1, Vec::new()),
+        Vector::new(Vec::<f64>::new()),
+        Vector::new(vec!["x".to_string()]),
+        "y".to_string(),
+    );
+    let err = MinMaxFitter::default()
+        .with_quantile_range(0.05, 0.95)
+        .fit(&empty_dataset)
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidData));
+}
+
+#[test]
+fn minmaxscaler_params_test() {
+    let iris_dataset = iris::load();
+    let mut minmax_scaler = MinMaxFitter::new(-1.0, 1.0).fit(&iris_dataset).unwrap();
+    let params = minmax_scaler.fitter().params();
+
+    assert_eq!(params.feature_names().len(), 5);
+    assert_eq!(params.scaled_range(), (-1.0, 1.0));
+    assert_eq!(params.min_values()[0], 1.0);
+    assert_eq!(params.max_values()[0], 150.0);
+
+    let mut restored_scaler = MinMaxFitter::from_params(params.clone()).unwrap();
+    assert_eq!(restored_scaler.fitter().fit_status(), &FitStatus::Fit);
+    assert_eq!(restored_scaler.fitter().params(), params);
+    assert_eq!(
+        restored_scaler.transform(&iris_dataset).unwrap().data(),
+        minmax_scaler.transform(&iris_dataset).unwrap().data()
+    );
+
+    let mismatched = MinMaxParams::new(
+        vec!["a".to_string(), "b".to_string()],
+        vec![0.0],
+        vec![1.0, 2.0],
+        (0.0, 1.0),
+        None,
+    );
+    let error = MinMaxFitter::<String>::from_params(mismatched).unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::InvalidParameters));
+}
+
+#[test]
+fn minmaxscaler_fit_vector_test() {
+    let target = Vector::new(vec![10.0, 25.0, 40.0, 15.0, 30.0]);
+    let scaler = MinMaxFitter::<f64>::new(-1.0, 1.0)
+        .fit_vector(&target)
+        .unwrap();
+
+    assert_eq!(scaler.min_max_values(), (10.0, 40.0));
+    let scaled = scaler.transform(&target).unwrap();
+    assert!((scaled[0] + 1.0).abs() < 1e-12);
+    assert!((scaled[2] - 1.0).abs() < 1e-12);
+    assert!(scaled[1].abs() < 1e-12);
+
+    let restored = scaler.inverse_transform(&scaled).unwrap();
+    for (original, value) in target.iter().zip(restored.iter()) {
+        assert!((original - value).abs() < 1e-12);
+    }
+    let prediction = scaler.inverse_transform(&Vector::new(vec![0.5])).unwrap();
+    assert!((prediction[0] - 32.5).abs() < 1e-12);
+
+    let error = MinMaxFitter::<f64>::default()
+        .fit_vector(&Vector::new(vec![3.0, 3.0]))
+        .unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::InvalidData));
+    assert!(MinMaxFitter::<f64>::default()
+        .fit_vector(&Vector::new(Vec::new()))
+        .is_err());
+}
+
+#[test]
+fn minmaxscaler_fit_columns_test() {
+    let iris_dataset = iris::load();
+    let mut minmax_scaler = MinMaxFitter::default()
+        .fit_columns(&iris_dataset, &["SepalLengthCm", "PetalWidthCm"])
+        .unwrap();
+    let transformed_dataset = minmax_scaler.transform(&iris_dataset).unwrap();
+
+    for idx in [0, 2, 3] {
+        assert_eq!(
+            transformed_dataset.data().col(idx).into_matrix(),
+            iris_dataset.data().col(idx).into_matrix()
+        );
+    }
+    for idx in [1, 4] {
+        let column = transformed_dataset.data().col(idx).into_matrix();
+        assert_eq!(column.min(Axes::Row)[0], 0.0);
+        assert_eq!(column.max(Axes::Row)[0], 1.0);
+    }
+
+    // The column selection survives a round trip through the parameters.
+    let params = minmax_scaler.fitter().params();
+    assert_eq!(params.scaled_columns().unwrap().len(), 2);
+    let mut restored_scaler = MinMaxFitter::from_params(params).unwrap();
+    assert_eq!(
+        restored_scaler.transform(&iris_dataset).unwrap().data(),
+        transformed_dataset.data()
+    );
+
+    let error = MinMaxFitter::<String>::default()
+        .fit_columns(&iris_dataset, &["SepalLengthCm", "Petals"])
+        .unwrap_err();
+    assert!(matches!(error.kind(), ErrorKind::InvalidParameters));
+}
+
+#[test]
+fn minmaxscaler_non_finite_scale_factor_test() {
+    // A zero range in the params gives an infinite scale factor.
+    let params = MinMaxParams::new(
+        vec!["a".to_string(), "b".to_string()],
+        vec![0.0, 2.0],
+        vec![1.0, 2.0],
+        (0.0, 1.0),
+        None,
+    );
+    let mut scaler = MinMaxFitter::<f64>::from_params(params).unwrap();
+    assert!(scaler.fitter().scale_factors()[1].is_infinite());
+
+    let data = Matrix::new(2, 2, vec![0.0, 2.0, 1.0, 2.0]);
+    let err = scaler.transform_matrix(&data).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidState));
+    assert!(err.to_string().contains("feature 1"));
+
+    let dataset = Dataset::new(
+        data,
+        Vector::new(vec![0.0, 1.0]),
+        Vector::new(vec!["a".to_string(), "b".to_string()]),
+        "target".to_string(),
+    );
+    let err = scaler.transform(&dataset).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidState));
+    assert!(err.to_string().contains("feature 1"));
+}
@@ -0,0 +1,51 @@
+use rust_ml::dataset::iris;
+use rust_ml::linalg::Vector;
+use rust_ml::model_selection::{group_kfold_indices, stratified_kfold_indices};
+
+#[test]
+fn group_kfold_indices_test() {
+    let groups = Vector::new(vec![1, 2, 1, 3, 2, 3]);
+    let folds = group_kfold_indices(&groups, 3).unwrap();
+
+    assert_eq!(folds.len(), 3);
+    let mut seen_groups = Vec::new();
+    for (train, test) in &folds {
+        assert_eq!(train.len() + test.len(), 6);
+        // Each test fold holds exactly one intact group, absent from training.
+        let test_group = groups[test[0]];
+        assert_eq!(test.len(), 2);
+        assert!(test.iter().all(|&idx| groups[idx] == test_group));
+        assert!(train.iter().all(|&idx| groups[idx] != test_group));
+        seen_groups.push(test_group);
+    }
+    seen_groups.sort();
+    assert_eq!(seen_groups, vec![1, 2, 3]);
+
+    assert!(group_kfold_indices(&groups, 4).is_err());
+}
+
+#[test]
+fn stratified_kfold_indices_test() {
+    let iris_dataset = iris::load();
+    let target = iris_dataset.target();
+
+    for seed in [None, Some(42)] {
+        let folds = stratified_kfold_indices(target, 5, seed).unwrap();
+        assert_eq!(folds.len(), 5);
+        let mut all_test: Vec<usize> = Vec::new();
+        for (train, test) in &folds {
+            assert_eq!(train.len(), 120);
+            assert_eq!(test.len(), 30);
+            for species in ["Iris-setosa", "Iris-versicolor", "Iris-virginica"] {
+                let count = test.iter().filter(|&&idx| target[idx] == species).count();
+                assert_eq!(count, 10);
+            }
+            all_test.extend(test);
+        }
+        all_test.sort();
+        assert_eq!(all_test, (0..150).collect::<Vec<usize>>());
+    }
+
+    assert!(stratified_kfold_indices(target, 51, None).is_err());
+    assert!(stratified_kfold_indices(target, 1, None).is_err());
+}
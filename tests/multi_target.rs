@@ -0,0 +1,48 @@
+use rust_ml::base::error::ErrorKind;
+use rust_ml::dataset::MultiTargetDataset;
+use rust_ml::linalg::{BaseMatrix, Matrix, Vector};
+
+#[test]
+fn multi_target_test() {
+    let dataset: MultiTargetDataset<Matrix<f64>> =
+        MultiTargetDataset::from_csv("./tests/data/multi_target.csv", &["y2", "y1"]).unwrap();
+
+    assert_eq!(4, dataset.data().rows());
+    assert_eq!(3, dataset.data().cols());
+    assert_eq!(4, dataset.target().rows());
+    assert_eq!(2, dataset.target().cols());
+    assert_eq!(
+        &Vector::new(vec!["x1".to_string(), "x2".to_string(), "x3".to_string()]),
+        dataset.data_columns()
+    );
+    assert_eq!(
+        &Vector::new(vec!["y2".to_string(), "y1".to_string()]),
+        dataset.target_columns()
+    );
+    assert_eq!(dataset.target()[[1, 0]], 1.5);
+    assert_eq!(dataset.target()[[1, 1]], 20.0);
+    assert_eq!(dataset.data()[[3, 2]], 6.0);
+
+    let missing: Result<MultiTargetDataset<Matrix<f64>>, _> =
+        MultiTargetDataset::from_csv("./tests/data/multi_target.csv", &["y3"]);
+    assert!(missing.is_err());
+
+    let duplicated: Result<MultiTargetDataset<Matrix<f64>>, _> =
+        MultiTargetDataset::from_csv("./tests/data/multi_target.csv", &["y1", "y1"]);
+    assert!(matches!(
+        duplicated.unwrap_err().kind(),
+        ErrorKind::InvalidParameters
+    ));
+}
+
+#[test]
+fn multi_target_from_csv_str_test() {
+    let dataset: MultiTargetDataset<Matrix<f64>> =
+        MultiTargetDataset::from_csv_str("x,y1,y2\n1,2,3\n4,5,6\n", &["y1", "y2"]).unwrap();
+
+    assert_eq!(dataset.data(), &Matrix::new(2, 1, vec![1.0, 4.0]));
+    assert_eq!(
+        dataset.target(),
+        &Matrix::new(2, 2, vec![2.0, 3.0, 5.0, 6.0])
+    );
+}
@@ -1,4 +1,7 @@
-use rust_ml::dataset::{pokemon, MixedDataset};
+use rand::seq::SliceRandom;
+use rust_ml::base::error::ErrorKind;
+use rust_ml::base::rng::seeded_rng;
+use rust_ml::dataset::{pokemon, MixedDataValue, MixedDataset};
 use rust_ml::linalg::{BaseMatrix, Vector};
 use rust_ml::preprocessing::encoders::onehotencoder::OneHotEncoderFitter;
 use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
@@ -15,3 +18,221 @@ fn onehotencoder_test() {
     assert_eq!(pokemon_ohe_dataset.data().rows(), 800);
     assert_eq!(pokemon_ohe_dataset.data().cols(), 46);
 }
+
+#[test]
+fn onehotencoder_column_mismatch_test() {
+    let pokemon_dataset: MixedDataset<Vector<String>> = pokemon::load();
+    let mut columns: Vec<String> = pokemon_dataset.data_columns().iter().cloned().collect();
+    columns.swap(1, 2);
+    let reordered_dataset = MixedDataset::new(
+        pokemon_dataset.data().clone(),
+        pokemon_dataset.target().clone(),
+        Vector::new(columns),
+        pokemon_dataset.target_column().to_string(),
+    );
+
+    let mut ohe = OneHotEncoderFitter::default()
+        .fit(&pokemon_dataset)
+        .unwrap();
+    let error = ohe.transform(&reordered_dataset).unwrap_err();
+
+    assert!(matches!(error.kind(), ErrorKind::InvalidState));
+    assert!(error.to_string().contains("Type 2"));
+}
+
+#[test]
+fn onehotencoder_sorted_categories_test() {
+    let pokemon_dataset: MixedDataset<Vector<String>> = pokemon::load();
+    let mut order: Vec<usize> = (0..pokemon_dataset.data().len()).collect();
+    order.shuffle(&mut seeded_rng(7));
+    let shuffled_dataset = MixedDataset::new(
+        order
+            .iter()
+            .map(|&idx| pokemon_dataset.data()[idx].clone())
+            .collect(),
+        Vector::new(
+            order
+                .iter()
+                .map(|&idx| pokemon_dataset.target()[idx].clone())
+                .collect::<Vec<String>>(),
+        ),
+        pokemon_dataset.data_columns().clone(),
+        pokemon_dataset.target_column().to_string(),
+    );
+
+    let encode = |dataset: &MixedDataset<Vector<String>>, sorted: bool| {
+        let mut ohe = OneHotEncoderFitter::default()
+            .with_sorted_categories(sorted)
+            .fit(dataset)
+            .unwrap();
+        ohe.transform(dataset).unwrap().data_columns().clone()
+    };
+
+    let sorted_columns = encode(&pokemon_dataset, true);
+    assert_eq!(sorted_columns, encode(&shuffled_dataset, true));
+    assert_ne!(
+        encode(&pokemon_dataset, false),
+        encode(&shuffled_dataset, false)
+    );
+
+    let type_columns: Vec<&String> = sorted_columns
+        .iter()
+        .filter(|name| name.starts_with("Type 1_"))
+        .collect();
+    let mut expected = type_columns.clone();
+    expected.sort();
+    assert_eq!(type_columns, expected);
+}
+
+#[test]
+fn onehotencoder_category_map_json_test() {
+    let pokemon_dataset: MixedDataset<Vector<String>> = pokemon::load();
+    let ohe = OneHotEncoderFitter::default()
+        .with_sorted_categories(true)
+        .fit(&pokemon_dataset)
+        .unwrap();
+
+    let json = ohe.fitter().category_map_json().unwrap();
+    assert!(json.contains("\"Type 1\":{"));
+    assert!(json.contains("\"Bug\":0"));
+    assert_eq!(json, ohe.fitter().category_map_json().unwrap());
+}
+
+#[test]
+fn onehotencoder_transform_inplace_test() {
+    let pokemon_dataset: MixedDataset<Vector<String>> = pokemon::load();
+    let mut ohe = OneHotEncoderFitter::default()
+        .fit(&pokemon_dataset)
+        .unwrap();
+
+    let encoded_dataset = ohe.transform_inplace(&pokemon_dataset).unwrap();
+    assert_eq!(encoded_dataset.data().len(), 800);
+    assert_eq!(encoded_dataset.data_columns().size(), 46);
+    assert!(encoded_dataset
+        .data()
+        .iter()
+        .flatten()
+        .all(|value| matches!(value, MixedDataValue::Numeric(_))));
+
+    let dense_dataset = ohe.transform(&pokemon_dataset).unwrap();
+    assert_eq!(encoded_dataset.data_columns(), dense_dataset.data_columns());
+    assert_eq!(
+        encoded_dataset.data()[0][1],
+        MixedDataValue::Numeric(dense_dataset.data()[[0, 1]])
+    );
+}
+
+#[test]
+fn onehotencoder_transform_missing_test() {
+    let dataset = MixedDataset::new(
+        vec![
+            vec![
+                MixedDataValue::Categorical("red".to_string()),
+                MixedDataValue::Numeric(1.0),
+            ],
+            vec![MixedDataValue::Missing, MixedDataValue::Numeric(2.0)],
+            vec![
+                MixedDataValue::Categorical("blue".to_string()),
+                MixedDataValue::Missing,
+            ],
+        ],
+        Vector::new(vec![0, 1, 0]),
+        Vector::new(vec!["color".to_string(), "size".to_string()]),
+        "label".to_string(),
+    );
+    let mut ohe = OneHotEncoderFitter::default().fit(&dataset).unwrap();
+
+    let encoded_dataset = ohe.transform_inplace(&dataset).unwrap();
+    assert_eq!(
+        encoded_dataset.data()[1],
+        vec![
+            MixedDataValue::Missing,
+            MixedDataValue::Missing,
+            MixedDataValue::Numeric(2.0)
+        ]
+    );
+    assert_eq!(
+        encoded_dataset.data()[2],
+        vec![
+            MixedDataValue::Numeric(0.0),
+            MixedDataValue::Numeric(1.0),
+            MixedDataValue::Missing
+        ]
+    );
+
+    let err = ohe.transform(&dataset).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::InvalidData));
+    assert!(err.to_string().contains("color"));
+}
+
+#[test]
+fn onehotencoder_partial_fit_test() {
+    let pokemon_dataset: MixedDataset<Vector<String>> = pokemon::load();
+    let halves: Vec<MixedDataset<Vector<String>>> = [0..400, 400..800]
+        .into_iter()
+        .map(|rows| {
+            MixedDataset::new(
+                pokemon_dataset.data()[rows.clone()].to_vec(),
+                Vector::new(pokemon_dataset.target().data()[rows].to_vec()),
+                pokemon_dataset.data_columns().clone(),
+                pokemon_dataset.target_column().to_string(),
+            )
+        })
+        .collect();
+
+    let mut fitter = OneHotEncoderFitter::default();
+    for half in halves.iter() {
+        fitter.partial_fit(half).unwrap();
+    }
+    let mut streamed = fitter.finalize().unwrap();
+    let full = OneHotEncoderFitter::default()
+        .fit(&pokemon_dataset)
+        .unwrap();
+
+    assert_eq!(streamed.fitter().fit_status(), &FitStatus::Fit);
+    assert_eq!(
+        streamed.fitter().category_map(),
+        full.fitter().category_map()
+    );
+    assert_eq!(
+        streamed.transform(&pokemon_dataset).unwrap().data().cols(),
+        46
+    );
+
+    let unfit = OneHotEncoderFitter::<String>::default();
+    assert!(matches!(
+        unfit.finalize().unwrap_err().kind(),
+        ErrorKind::InvalidState
+    ));
+}
+
+#[test]
+fn onehotencoder_sorted_partial_fit_test() {
+    let batch = |categories: &[&str]| {
+        MixedDataset::new(
+            categories
+                .iter()
+                .map(|category| vec![MixedDataValue::Categorical(category.to_string())])
+                .collect(),
+            Vector::new(vec![0; categories.len()]),
+            Vector::new(vec!["color".to_string()]),
+            "label".to_string(),
+        )
+    };
+
+    let mut fitter = OneHotEncoderFitter::default().with_sorted_categories(true);
+    fitter.partial_fit(&batch(&["red", "blue"])).unwrap();
+    let encoder = fitter.finalize().unwrap();
+    let color_map = &encoder.fitter().category_map()["color"];
+    assert_eq!(color_map["blue"], 0);
+    assert_eq!(color_map["red"], 1);
+
+    // Streaming a category that sorts first shifts the existing indices.
+    let mut fitter = encoder.fitter().clone();
+    fitter.partial_fit(&batch(&["amber"])).unwrap();
+    let encoder = fitter.finalize().unwrap();
+    let color_map = &encoder.fitter().category_map()["color"];
+    assert_eq!(color_map["amber"], 0);
+    assert_eq!(color_map["blue"], 1);
+    assert_eq!(color_map["red"], 2);
+}
@@ -1,4 +1,4 @@
-use rust_ml::dataset::pokemon;
+use rust_ml::dataset::{pokemon, MixedDataValue};
 use rust_ml::linalg::Vector;
 
 #[test]
@@ -24,3 +24,112 @@ fn pokemon_test() {
     );
     assert_eq!("Legendary", pokemon_dataset.target_column());
 }
+
+#[test]
+fn pokemon_get_test() {
+    let pokemon_dataset = pokemon::load();
+
+    assert_eq!(800, pokemon_dataset.iter_rows().count());
+    assert_eq!(
+        &MixedDataValue::Categorical("Grass".to_string()),
+        pokemon_dataset.get(0, "Type 1").unwrap()
+    );
+    assert_eq!(
+        &MixedDataValue::Numeric(45.0),
+        pokemon_dataset.get(0, "HP").unwrap()
+    );
+    assert!(pokemon_dataset.get(0, "Name").is_err());
+    assert!(pokemon_dataset.get(800, "HP").is_err());
+}
+
+#[test]
+fn pokemon_add_column_test() {
+    let pokemon_dataset = pokemon::load();
+    let flags = pokemon_dataset
+        .iter_rows()
+        .map(|row| match &row[2] {
+            MixedDataValue::Categorical(t) if t.is_empty() => "single".to_string(),
+            _ => "dual".to_string(),
+        })
+        .collect::<Vec<String>>();
+
+    let flagged = pokemon_dataset
+        .add_categorical_column("Typing", flags)
+        .unwrap();
+    assert_eq!(12, flagged.data_columns().size());
+    assert_eq!(12, flagged.data()[0].len());
+    assert_eq!(
+        &MixedDataValue::Categorical("dual".to_string()),
+        flagged.get(0, "Typing").unwrap()
+    );
+
+    let numbered = flagged
+        .add_numeric_column("Row", (0..800).map(|i| i as f64).collect())
+        .unwrap();
+    assert_eq!(
+        &MixedDataValue::Numeric(799.0),
+        numbered.get(799, "Row").unwrap()
+    );
+
+    assert!(pokemon_dataset
+        .add_numeric_column("Short", vec![1.0, 2.0])
+        .is_err());
+    assert!(pokemon_dataset
+        .add_categorical_column("HP", vec![String::new(); 800])
+        .is_err());
+}
+
+#[test]
+fn pokemon_set_column_type_test() {
+    let pokemon_dataset = pokemon::load();
+
+    let categorical = pokemon_dataset.set_column_categorical("HP").unwrap();
+    assert!(categorical
+        .iter_rows()
+        .all(|row| matches!(row[4], MixedDataValue::Categorical(_))));
+    assert_eq!(
+        &MixedDataValue::Categorical("45".to_string()),
+        categorical.get(0, "HP").unwrap()
+    );
+
+    let numeric = categorical.set_column_numeric("HP").unwrap();
+    assert_eq!(pokemon_dataset.data(), numeric.data());
+
+    assert!(pokemon_dataset.set_column_numeric("Type 1").is_err());
+    assert!(pokemon_dataset.set_column_numeric("Name").is_err());
+}
+
+#[test]
+fn pokemon_split_by_type_test() {
+    let pokemon_dataset = pokemon::load();
+    let (numeric, categorical) = pokemon_dataset.split_by_type();
+
+    assert_eq!(numeric.data_columns().size(), 9);
+    assert_eq!(categorical.data_columns().size(), 2);
+    assert_eq!(
+        categorical.data_columns(),
+        &Vector::new(vec!["Type 1".to_string(), "Type 2".to_string()])
+    );
+    assert_eq!(numeric.data().len(), 800);
+    assert!(numeric.data()[0]
+        .iter()
+        .all(|value| matches!(value, MixedDataValue::Numeric(_))));
+    assert_eq!(categorical.target(), pokemon_dataset.target());
+}
+
+#[test]
+fn pokemon_typed_column_test() {
+    let pokemon_dataset = pokemon::load();
+
+    let hp = pokemon_dataset.numeric_column("HP").unwrap();
+    assert_eq!(800, hp.len());
+    assert_eq!(45.0, hp[0]);
+
+    let types = pokemon_dataset.categorical_column("Type 1").unwrap();
+    assert_eq!(800, types.len());
+    assert_eq!("Grass", types[0]);
+
+    assert!(pokemon_dataset.numeric_column("Type 1").is_err());
+    assert!(pokemon_dataset.categorical_column("HP").is_err());
+    assert!(pokemon_dataset.numeric_column("Name").is_err());
+}
@@ -0,0 +1,16 @@
+use rand::seq::index::sample;
+use rust_ml::base::rng::{default_rng, seeded_rng};
+
+#[test]
+fn seeded_rng_test() {
+    let first = sample(&mut seeded_rng(11), 150, 20).into_vec();
+    let second = sample(&mut seeded_rng(11), 150, 20).into_vec();
+    let other_seed = sample(&mut seeded_rng(12), 150, 20).into_vec();
+
+    assert_eq!(first, second);
+    assert_ne!(first, other_seed);
+    assert_eq!(
+        sample(&mut default_rng(), 150, 20).into_vec(),
+        sample(&mut default_rng(), 150, 20).into_vec()
+    );
+}
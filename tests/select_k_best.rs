@@ -0,0 +1,40 @@
+use rand::Rng;
+use rust_ml::base::rng::seeded_rng;
+use rust_ml::dataset::Dataset;
+use rust_ml::linalg::{BaseMatrix, Matrix, Vector};
+use rust_ml::preprocessing::selection::select_k_best::SelectKBestFitter;
+use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+
+#[test]
+fn select_k_best_test() {
+    // Columns x1 and x4 drive the target, the others are noise.
+    let mut rng = seeded_rng(7);
+    let num_rows = 200;
+    let mut data = Vec::with_capacity(num_rows * 5);
+    let mut target = Vec::with_capacity(num_rows);
+    for _ in 0..num_rows {
+        let row: Vec<f64> = (0..5).map(|_| rng.gen_range(-1.0..1.0)).collect();
+        target.push(2.0 * row[1] - 3.0 * row[4] + 0.1 * rng.gen_range(-1.0..1.0));
+        data.extend(row);
+    }
+    let dataset = Dataset::new(
+        Matrix::new(num_rows, 5, data),
+        Vector::new(target),
+        Vector::new((0..5).map(|i| format!("x{}", i)).collect::<Vec<String>>()),
+        "y".to_string(),
+    );
+
+    let mut selector = SelectKBestFitter::new(2).fit(&dataset).unwrap();
+    let reduced_dataset = selector.transform(&dataset).unwrap();
+
+    assert_eq!(selector.fitter().fit_status(), &FitStatus::Fit);
+    assert_eq!(
+        selector.fitter().selected_columns(),
+        &Vector::new(vec!["x1".to_string(), "x4".to_string()])
+    );
+    assert_eq!(reduced_dataset.data().cols(), 2);
+    assert_eq!(reduced_dataset.data().col(1)[3], dataset.data()[[3, 4]]);
+
+    assert!(SelectKBestFitter::new(6).fit(&dataset).is_err());
+    assert!(SelectKBestFitter::new(0).fit(&dataset).is_err());
+}
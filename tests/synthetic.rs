@@ -0,0 +1,51 @@
+use rust_ml::dataset::synthetic::{make_blobs, make_regression, meshgrid};
+use rust_ml::linalg::BaseMatrix;
+
+#[test]
+fn make_blobs_test() {
+    let blobs = make_blobs(100, 2, 3, 0.5, 42);
+
+    assert_eq!(100, blobs.data().rows());
+    assert_eq!(2, blobs.data().cols());
+    assert_eq!(100, blobs.target().size());
+    let mut labels: Vec<usize> = blobs.target().iter().cloned().collect();
+    labels.sort();
+    labels.dedup();
+    assert_eq!(vec![0, 1, 2], labels);
+
+    // The same seed reproduces the same dataset.
+    let again = make_blobs(100, 2, 3, 0.5, 42);
+    assert_eq!(blobs.data(), again.data());
+    assert_ne!(blobs.data(), make_blobs(100, 2, 3, 0.5, 7).data());
+}
+
+#[test]
+fn make_regression_test() {
+    let (dataset, coefficients) = make_regression(200, 3, 0.01, 42);
+
+    assert_eq!(200, dataset.data().rows());
+    assert_eq!(3, dataset.data().cols());
+    assert_eq!(3, coefficients.len());
+
+    // Recover the coefficients with the normal equations.
+    let x = dataset.data();
+    let fitted = (x.transpose() * x)
+        .solve(x.transpose() * dataset.target())
+        .unwrap();
+    for (fitted, truth) in fitted.iter().zip(coefficients.iter()) {
+        assert!((fitted - truth).abs() < 1e-2);
+    }
+}
+
+#[test]
+fn meshgrid_test() {
+    let grid = meshgrid((-1.0, 3.0), (0.0, 0.5), 5);
+
+    assert_eq!(25, grid.rows());
+    assert_eq!(2, grid.cols());
+    assert_eq!(&[-1.0, 0.0], grid.row(0).raw_slice());
+    assert_eq!(&[3.0, 0.0], grid.row(4).raw_slice());
+    assert_eq!(&[-1.0, 0.5], grid.row(20).raw_slice());
+    assert_eq!(&[3.0, 0.5], grid.row(24).raw_slice());
+    assert_eq!(&[0.0, 0.125], grid.row(6).raw_slice());
+}
@@ -0,0 +1,41 @@
+use rust_ml::dataset::{iris, Dataset};
+use rust_ml::linalg::{BaseMatrix, Matrix};
+use rust_ml::preprocessing::winsorize::WinsorizerFitter;
+use rust_ml::preprocessing::{FitStatus, Preprocessor, PreprocessorFitter};
+
+#[test]
+fn winsorizer_test() {
+    let iris_dataset = iris::load();
+    let mut data = iris_dataset.data().data().clone();
+    // Inject an extreme SepalLengthCm value into the first row.
+    data[1] = 1000.0;
+    let outlier_dataset = Dataset::new(
+        Matrix::new(150, 5, data),
+        iris_dataset.target().clone(),
+        iris_dataset.data_columns().clone(),
+        iris_dataset.target_column().to_string(),
+    );
+
+    let winsorizer_fitter = WinsorizerFitter::new(0.01, 0.99);
+    let mut winsorizer = winsorizer_fitter.fit(&outlier_dataset).unwrap();
+    let winsorized_dataset = winsorizer.transform(&outlier_dataset).unwrap();
+
+    let upper_bound = winsorizer.fitter().upper_bounds()[1];
+    assert_eq!(winsorizer.fitter().fit_status(), &FitStatus::Fit);
+    assert!(upper_bound < 1000.0);
+    assert_eq!(winsorized_dataset.data()[[0, 1]], upper_bound);
+    // An interior value is left unchanged.
+    assert_eq!(
+        winsorized_dataset.data()[[1, 1]],
+        outlier_dataset.data()[[1, 1]]
+    );
+    assert!(winsorized_dataset
+        .data()
+        .col(1)
+        .iter()
+        .all(|&v| v >= winsorizer.fitter().lower_bounds()[1] && v <= upper_bound));
+
+    assert!(WinsorizerFitter::new(0.9, 0.1)
+        .fit(&outlier_dataset)
+        .is_err());
+}